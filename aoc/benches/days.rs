@@ -0,0 +1,86 @@
+use std::hint::black_box;
+
+use common::input::{fixture_path, read, Source};
+use common::{Generate, Solution};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+// criterion's default; drop it for anything that takes a noticeable fraction of a second
+const QUICK: usize = 100;
+const SLOW: usize = 10;
+
+fn example(day: u8) -> String {
+    read(day, &Source::File(fixture_path(day, "example.txt"))).unwrap()
+}
+
+// a made-up input about the size of a real one. always the same seed, so runs compare
+fn generated<G: Generate>(size: usize) -> String {
+    G::generate(size, 2021)
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion, name: &str, input: &str, sample_size: usize) {
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    group.sample_size(sample_size);
+
+    group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+        b.iter(|| S::parse_input(black_box(input)))
+    });
+
+    let parsed = S::parse_input(input).unwrap();
+    group.bench_with_input(BenchmarkId::new("part_1", name), &parsed, |b, parsed| {
+        b.iter(|| S::part_1(black_box(parsed)))
+    });
+    group.bench_with_input(BenchmarkId::new("part_2", name), &parsed, |b, parsed| {
+        b.iter(|| S::part_2(black_box(parsed)))
+    });
+
+    group.finish();
+}
+
+fn examples(c: &mut Criterion) {
+    bench_day::<day01::Day01>(c, "example", &example(1), QUICK);
+    bench_day::<day02::Day02>(c, "example", &example(2), QUICK);
+    bench_day::<day03::Day03>(c, "example", &example(3), QUICK);
+    bench_day::<day04::Day04>(c, "example", &example(4), QUICK);
+    bench_day::<day05::Day05>(c, "example", &example(5), QUICK);
+    bench_day::<day06::Day06>(c, "example", &example(6), QUICK);
+    bench_day::<day07::Day07>(c, "example", &example(7), QUICK);
+    bench_day::<day08::Day08>(c, "example", &example(8), QUICK);
+    bench_day::<day09::Day09>(c, "example", &example(9), QUICK);
+    bench_day::<day10::Day10>(c, "example", &example(10), QUICK);
+    bench_day::<day11::Day11>(c, "example", &example(11), QUICK);
+    bench_day::<day12::Day12>(c, "example", &example(12), QUICK);
+    bench_day::<day13::Day13>(c, "example", &example(13), QUICK);
+    bench_day::<day14::Day14>(c, "example", &example(14), QUICK);
+    bench_day::<day15::Day15>(c, "example", &example(15), QUICK);
+    bench_day::<day16::Day16>(c, "example", &example(16), QUICK);
+    bench_day::<day17::Day17>(c, "example", &example(17), QUICK);
+    bench_day::<day18::Day18>(c, "example", &example(18), QUICK);
+    bench_day::<day19::Day19>(c, "example", &example(19), SLOW);
+}
+
+// synthetic inputs nearer the size of a real one (or as big as is practical, for the days
+// whose answers blow up), for everyone who doesn't have the real inputs to hand
+fn generated_inputs(c: &mut Criterion) {
+    bench_day::<day01::Day01>(c, "generated", &generated::<day01::Day01>(2000), QUICK);
+    bench_day::<day02::Day02>(c, "generated", &generated::<day02::Day02>(1000), QUICK);
+    bench_day::<day03::Day03>(c, "generated", &generated::<day03::Day03>(1000), QUICK);
+    bench_day::<day04::Day04>(c, "generated", &generated::<day04::Day04>(100), SLOW);
+    bench_day::<day05::Day05>(c, "generated", &generated::<day05::Day05>(500), SLOW);
+    bench_day::<day06::Day06>(c, "generated", &generated::<day06::Day06>(300), SLOW);
+    bench_day::<day07::Day07>(c, "generated", &generated::<day07::Day07>(1000), SLOW);
+    bench_day::<day08::Day08>(c, "generated", &generated::<day08::Day08>(200), SLOW);
+    bench_day::<day09::Day09>(c, "generated", &generated::<day09::Day09>(100), SLOW);
+    bench_day::<day10::Day10>(c, "generated", &generated::<day10::Day10>(100), QUICK);
+    bench_day::<day11::Day11>(c, "generated", &generated::<day11::Day11>(10), QUICK);
+    bench_day::<day12::Day12>(c, "generated", &generated::<day12::Day12>(10), SLOW);
+    bench_day::<day13::Day13>(c, "generated", &generated::<day13::Day13>(800), QUICK);
+    bench_day::<day14::Day14>(c, "generated", &generated::<day14::Day14>(20), QUICK);
+    bench_day::<day15::Day15>(c, "generated", &generated::<day15::Day15>(100), SLOW);
+    bench_day::<day16::Day16>(c, "generated", &generated::<day16::Day16>(300), QUICK);
+    bench_day::<day17::Day17>(c, "generated", &generated::<day17::Day17>(100), SLOW);
+    bench_day::<day18::Day18>(c, "generated", &generated::<day18::Day18>(100), SLOW);
+    bench_day::<day19::Day19>(c, "generated", &generated::<day19::Day19>(10), SLOW);
+}
+
+criterion_group!(benches, examples, generated_inputs);
+criterion_main!(benches);
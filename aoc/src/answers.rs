@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+// known-good answers, kept in a TOML file next to the days:
+//
+//   [day01]
+//   part_1 = 1451
+//   part_2 = "1395"
+//
+// anything too big for a TOML integer (or a day 13 picture) can go in as a string. the
+// examples in each day's fixtures have answers kept the same way, by example name instead
+// of day (see examples.rs)
+#[derive(Deserialize, Default, Debug)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+#[derive(Deserialize, Default, Debug)]
+struct DayAnswers {
+    part_1: Option<Answer>,
+    part_2: Option<Answer>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Error, Debug)]
+pub enum AnswersError {
+    #[error("failed to read {}: {source}", path.display())]
+    Unreadable { path: PathBuf, source: io::Error },
+    #[error("failed to parse {}: {source}", path.display())]
+    Invalid { path: PathBuf, source: toml::de::Error },
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+// how a --check run went, overall
+#[derive(Serialize, Default, Debug)]
+pub struct Tally {
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+    pub skipped: usize,
+}
+
+impl Tally {
+    pub fn record(&mut self, verdict: &Verdict) {
+        match verdict {
+            Verdict::Pass => self.passed += 1,
+            Verdict::Fail { .. } => self.failed += 1,
+            Verdict::Missing => self.missing += 1,
+        }
+    }
+}
+
+pub fn default_path() -> PathBuf {
+    common::input::workspace_root().join("answers.toml")
+}
+
+impl Answers {
+    // no file just means we don't know any answers yet
+    pub fn load(path: &Path) -> Result<Answers, AnswersError> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|source| AnswersError::Invalid { path: path.to_owned(), source }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(source) => Err(AnswersError::Unreadable { path: path.to_owned(), source }),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<String> {
+        self.get_named(&format!("day{:02}", day), part)
+    }
+
+    // the names in the file, e.g. `day01` or, for the examples, `example`
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    pub fn get_named(&self, name: &str, part: u8) -> Option<String> {
+        let answers = self.0.get(name)?;
+        let answer = match part {
+            1 => answers.part_1.as_ref(),
+            2 => answers.part_2.as_ref(),
+            _ => None,
+        }?;
+
+        Some(match answer {
+            Answer::Number(n) => n.to_string(),
+            Answer::Text(s) => s.clone(),
+        })
+    }
+
+    pub fn check(&self, day: u8, part: u8, actual: &str) -> Verdict {
+        self.check_named(&format!("day{:02}", day), part, actual)
+    }
+
+    pub fn check_named(&self, name: &str, part: u8, actual: &str) -> Verdict {
+        match self.get_named(name, part) {
+            None => Verdict::Missing,
+            // don't let trailing whitespace on a picture decide things
+            Some(expected) if expected.trim_end() == actual.trim_end() => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+        }
+    }
+
+    // like `check`, but for a part that might not have an answer at all. an unsolved part
+    // isn't a regression unless we used to have an answer for it
+    pub fn judge(&self, day: u8, part: u8, result: &Result<String, String>) -> Verdict {
+        match result {
+            Ok(actual) => self.check(day, part, actual),
+            Err(_) => match self.get(day, part) {
+                Some(expected) => Verdict::Fail { expected },
+                None => Verdict::Missing,
+            },
+        }
+    }
+}
+
+#[test]
+pub fn test_answers() {
+    let answers: Answers = toml::from_str(r#"
+[day01]
+part_1 = 7
+part_2 = "5"
+
+[day13]
+part_2 = """
+#.#
+.#.
+"""
+"#).unwrap();
+
+    assert_eq!(answers.check(1, 1, "7"), Verdict::Pass);
+    assert_eq!(answers.check(1, 2, "6"), Verdict::Fail { expected: "5".to_owned() });
+    assert_eq!(answers.check(2, 1, "7"), Verdict::Missing);
+    assert_eq!(answers.check(13, 1, "17"), Verdict::Missing);
+    assert_eq!(answers.check(13, 2, "#.#\n.#.\n\n"), Verdict::Pass);
+
+    assert_eq!(answers.judge(1, 1, &Err("not solved yet".to_owned())), Verdict::Fail { expected: "7".to_owned() });
+    assert_eq!(answers.judge(13, 1, &Err("not solved yet".to_owned())), Verdict::Missing);
+}
+
+#[test]
+pub fn test_missing_file() {
+    let answers = Answers::load(Path::new("definitely/not/answers.toml")).unwrap();
+    assert_eq!(answers.get(1, 1), None);
+}
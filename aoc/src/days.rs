@@ -0,0 +1,201 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use common::input::InputError;
+use common::{trace, Generate, Solution};
+use grid::image::{Image, Render};
+
+use crate::profile::{self, Usage};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub struct Timed<T> {
+    pub result: Result<T, String>,
+    pub elapsed: Duration,
+    // only with --profile
+    pub usage: Option<Usage>,
+}
+
+pub struct Report {
+    pub day: u8,
+    pub parse: Timed<()>,
+    pub parts: Vec<(u8, Timed<String>)>,
+    // only if it was asked for, and None if the day doesn't draw one
+    pub picture: Option<Result<Image, String>>,
+}
+
+// counting the allocations doesn't take long, but it's left out of the timing all the same
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration, Option<Usage>) {
+    let ((result, elapsed), usage) = profile::measure(|| {
+        let start = Instant::now();
+        let result = f();
+        (result, start.elapsed())
+    });
+    (result, elapsed, usage)
+}
+
+pub fn solve<S: Solution>(input: &str, part: Option<u8>) -> Report {
+    let (parsed, elapsed, usage) = timed(|| S::parse_input(input));
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            // nothing to solve if we couldn't even parse it
+            return Report { day: S::DAY, parse: Timed { result: Err(e.to_string()), elapsed, usage }, parts: Vec::new(), picture: None };
+        }
+    };
+
+    let mut parts = Vec::new();
+    if part.is_none() || part == Some(1) {
+        let (result, elapsed, usage) = timed(|| S::part_1(&parsed));
+        parts.push((1, Timed { result: result.map(|a| a.to_string()).map_err(|e| e.to_string()), elapsed, usage }));
+    }
+
+    if part.is_none() || part == Some(2) {
+        let (result, elapsed, usage) = timed(|| S::part_2(&parsed));
+        parts.push((2, Timed { result: result.map(|b| b.to_string()).map_err(|e| e.to_string()), elapsed, usage }));
+    }
+
+    Report { day: S::DAY, parse: Timed { result: Ok(()), elapsed, usage }, parts, picture: None }
+}
+
+pub fn run(day: u8, input: &str, part: Option<u8>) -> Option<Report> {
+    let report = match day {
+        1 => solve::<day01::Day01>(input, part),
+        2 => solve::<day02::Day02>(input, part),
+        3 => solve::<day03::Day03>(input, part),
+        4 => solve::<day04::Day04>(input, part),
+        5 => solve::<day05::Day05>(input, part),
+        6 => solve::<day06::Day06>(input, part),
+        7 => solve::<day07::Day07>(input, part),
+        8 => solve::<day08::Day08>(input, part),
+        9 => solve::<day09::Day09>(input, part),
+        10 => solve::<day10::Day10>(input, part),
+        11 => solve::<day11::Day11>(input, part),
+        12 => solve::<day12::Day12>(input, part),
+        13 => solve::<day13::Day13>(input, part),
+        14 => solve::<day14::Day14>(input, part),
+        15 => solve::<day15::Day15>(input, part),
+        16 => solve::<day16::Day16>(input, part),
+        17 => solve::<day17::Day17>(input, part),
+        18 => solve::<day18::Day18>(input, part),
+        19 => solve::<day19::Day19>(input, part),
+        20 => solve::<day20::Day20>(input, part),
+        _ => return None,
+    };
+
+    Some(report)
+}
+
+// a step of a --trace, on stderr so it doesn't get mixed up with the answers
+fn print_step(day: u8, step: fmt::Arguments, state: &dyn fmt::Display) {
+    let state = state.to_string();
+    if state.contains('\n') {
+        eprintln!("[day {:02}] {}:\n{}", day, step, state.trim_end());
+    } else {
+        eprintln!("[day {:02}] {}: {}", day, step, state);
+    }
+}
+
+// loads and runs each of `days`, in order. with the `parallel` feature they all run at once,
+// which makes `run all` a lot quicker but the timings noisier (as does `trace`). not while
+// profiling, though, or each day's allocations would get mixed up with the others'
+pub fn run_all(days: &[u8], input: Option<&str>, part: Option<u8>, trace: bool, picture: bool) -> Vec<(u8, Result<Option<Report>, InputError>)> {
+    let load_and_run = |&day: &u8| {
+        let report = common::input::load(day, input).map(|input| {
+            let mut report = match trace {
+                true => trace::observe(print_step, || run(day, &input, part)),
+                false => run(day, &input, part),
+            }?;
+            if picture {
+                report.picture = render(day, &input);
+            }
+            Some(report)
+        });
+        (day, report)
+    };
+
+    #[cfg(feature = "parallel")]
+    if !profile::enabled() {
+        return days.par_iter().map(load_and_run).collect();
+    }
+
+    days.iter().map(load_and_run).collect()
+}
+
+fn rendered<R: Render>(input: &str) -> Result<Image, String> {
+    let input = R::parse_input(input).map_err(|e| e.to_string())?;
+    R::render(&input).map_err(|e| e.to_string())
+}
+
+// a picture of the day, for the days that have something worth drawing
+pub fn render(day: u8, input: &str) -> Option<Result<Image, String>> {
+    let image = match day {
+        5 => rendered::<day05::Day05>(input),
+        9 => rendered::<day09::Day09>(input),
+        11 => rendered::<day11::Day11>(input),
+        13 => rendered::<day13::Day13>(input),
+        15 => rendered::<day15::Day15>(input),
+        _ => return None,
+    };
+
+    Some(image)
+}
+
+fn recorded<S: Solution>(input: &str, part: u8) -> Result<Vec<trace::Step>, String> {
+    let input = S::parse_input(input).map_err(|e| e.to_string())?;
+    let (result, steps) = trace::record(|| match part {
+        1 => S::part_1(&input).map(|_| ()),
+        _ => S::part_2(&input).map(|_| ()),
+    });
+    result.map_err(|e| e.to_string())?;
+    Ok(steps)
+}
+
+// every step of the days that are simulations, for stepping through one at a time. only
+// the parts with a manageable number of steps, mind: nobody wants to page through day 6's
+// 80 days of several hundred thousand fish
+pub fn simulate(day: u8, input: &str) -> Option<Result<Vec<trace::Step>, String>> {
+    let steps = match day {
+        11 => recorded::<day11::Day11>(input, 2),
+        13 => recorded::<day13::Day13>(input, 2),
+        14 => recorded::<day14::Day14>(input, 2),
+        18 => recorded::<day18::Day18>(input, 1),
+        _ => return None,
+    };
+
+    Some(steps)
+}
+
+fn generated<G: Generate>(size: usize, seed: u64) -> String {
+    G::generate(size, seed)
+}
+
+// without a size, something about as big as the real input (or as big as is practical,
+// for days where the answers blow up)
+pub fn generate(day: u8, size: Option<usize>, seed: u64) -> Option<String> {
+    let input = match day {
+        1 => generated::<day01::Day01>(size.unwrap_or(2000), seed),
+        2 => generated::<day02::Day02>(size.unwrap_or(1000), seed),
+        3 => generated::<day03::Day03>(size.unwrap_or(1000), seed),
+        4 => generated::<day04::Day04>(size.unwrap_or(100), seed),
+        5 => generated::<day05::Day05>(size.unwrap_or(500), seed),
+        6 => generated::<day06::Day06>(size.unwrap_or(300), seed),
+        7 => generated::<day07::Day07>(size.unwrap_or(1000), seed),
+        8 => generated::<day08::Day08>(size.unwrap_or(200), seed),
+        9 => generated::<day09::Day09>(size.unwrap_or(100), seed),
+        10 => generated::<day10::Day10>(size.unwrap_or(100), seed),
+        11 => generated::<day11::Day11>(size.unwrap_or(10), seed),
+        12 => generated::<day12::Day12>(size.unwrap_or(10), seed),
+        13 => generated::<day13::Day13>(size.unwrap_or(800), seed),
+        14 => generated::<day14::Day14>(size.unwrap_or(20), seed),
+        15 => generated::<day15::Day15>(size.unwrap_or(100), seed),
+        16 => generated::<day16::Day16>(size.unwrap_or(300), seed),
+        17 => generated::<day17::Day17>(size.unwrap_or(100), seed),
+        18 => generated::<day18::Day18>(size.unwrap_or(100), seed),
+        19 => generated::<day19::Day19>(size.unwrap_or(10), seed),
+        20 => generated::<day20::Day20>(size.unwrap_or(100), seed),
+        _ => return None,
+    };
+
+    Some(input)
+}
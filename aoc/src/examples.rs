@@ -0,0 +1,138 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use common::input;
+
+use crate::answers::{Answers, Verdict};
+use crate::days;
+
+// the puzzle examples, kept as dayNN/fixtures/<name>.txt with their answers alongside in
+// dayNN/fixtures/answers.toml, in the same format as the real ones:
+//
+//   [example]
+//   part_1 = 7
+//   part_2 = 5
+//
+// so another example is just another file and its answers, no Rust needed. a part without
+// an answer isn't checked, since some examples (like most of day 16's) only cover one part
+
+// each example as it might have been saved by someone else's editor. they should all get
+// the same answers as the original
+type Variant = (&'static str, fn(&str) -> String);
+
+const VARIANTS: [Variant; 5] = [
+    ("", |example| example.to_owned()),
+    (" with CRLF line endings", |example| example.replace('\n', "\r\n")),
+    (" without a trailing newline", |example| example.trim_end().to_owned()),
+    (" with blank lines around it", |example| format!("\n  \n{}\n\n", example)),
+    (" with trailing spaces", |example| example.lines().map(|line| format!("{} \t\n", line)).collect()),
+];
+
+pub fn answers_path(day: u8) -> PathBuf {
+    input::fixture_path(day, "answers.toml")
+}
+
+// the names of the examples for `day`, i.e. the .txt files in its fixtures, in order
+fn discover(day: u8) -> io::Result<BTreeSet<String>> {
+    let dir = input::fixture_path(day, "");
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        // a day with no fixtures just has no examples yet
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(BTreeSet::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut names = BTreeSet::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "txt") {
+            if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+                names.insert(name.to_owned());
+            }
+        }
+    }
+
+    Ok(names)
+}
+
+// runs every example for `day` (in every variant), returning how many there were and a line
+// for each thing that went wrong with them
+pub fn check(day: u8) -> (usize, Vec<String>) {
+    let mut problems = Vec::new();
+    let names = match discover(day) {
+        Ok(names) => names,
+        Err(e) => return (0, vec![format!("Day {:02}: failed to list the fixtures: {}", day, e)]),
+    };
+    let answers = match Answers::load(&answers_path(day)) {
+        Ok(answers) => answers,
+        Err(e) => return (names.len(), vec![format!("Day {:02}: {}", day, e)]),
+    };
+
+    for name in answers.names().filter(|name| !names.contains(*name)) {
+        problems.push(format!("Day {:02}: there are answers for `{}`, but no {}.txt", day, name, name));
+    }
+
+    for name in &names {
+        if !answers.names().any(|n| n == name) {
+            problems.push(format!("Day {:02} {}: no answers for it in {}", day, name, answers_path(day).display()));
+            continue;
+        }
+
+        let path = input::fixture_path(day, &format!("{}.txt", name));
+        let example = match fs::read_to_string(&path) {
+            Ok(example) => example,
+            Err(e) => {
+                problems.push(format!("Day {:02} {}: failed to read {}: {}", day, name, path.display(), e));
+                continue;
+            }
+        };
+
+        for (variant, vary) in VARIANTS {
+            let label = format!("Day {:02} {}{}", day, name, variant);
+            check_example(day, name, &vary(&example), &answers, &label, &mut problems);
+        }
+    }
+
+    (names.len(), problems)
+}
+
+fn check_example(day: u8, name: &str, example: &str, answers: &Answers, label: &str, problems: &mut Vec<String>) {
+    let report = match days::run(day, example, None) {
+        Some(report) => report,
+        None => return problems.push(format!("{}: not solved yet", label)),
+    };
+    if let Err(e) = &report.parse.result {
+        return problems.push(format!("{}: failed to parse:\n{}", label, e));
+    }
+
+    for (part, timed) in &report.parts {
+        let Some(expected) = answers.get_named(name, *part) else {
+            continue;
+        };
+        match &timed.result {
+            Ok(actual) => {
+                if let Verdict::Fail { expected } = answers.check_named(name, *part, actual) {
+                    problems.push(format!("{} part {}: expected {}, got {}", label, part, expected.trim_end(), actual.trim_end()));
+                }
+            }
+            Err(e) => problems.push(format!("{} part {}: expected {}, got an error: {}", label, part, expected.trim_end(), e)),
+        }
+    }
+}
+
+#[test]
+pub fn test_examples() {
+    let mut examples = 0;
+    let mut problems = Vec::new();
+    for day in 1..=crate::LAST_DAY {
+        let (count, mut found) = check(day);
+        examples += count;
+        problems.append(&mut found);
+    }
+
+    assert!(problems.is_empty(), "\n{}", problems.join("\n"));
+    // every solved day has at least the one from the puzzle
+    assert!(examples >= 19);
+}
@@ -0,0 +1,231 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use common::input::{self, Source};
+
+pub const YEAR: u16 = 2021;
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+// the value of the `session` cookie from a logged in browser
+pub const SESSION_VAR: &str = "AOC_SESSION";
+// where fetched inputs are kept, as $AOC_CACHE_DIR/2021/dayNN.txt. defaults to ~/.cache/aoc
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+const USER_AGENT: &str = concat!("aoc-2021-runner/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Error, Debug)]
+pub enum FetchError {
+    #[error("day {day} isn't cached, and {} isn't set so it can't be fetched", SESSION_VAR)]
+    NoSession { day: u8 },
+    #[error("failed to fetch {url}: {source}")]
+    Http { url: String, source: Box<dyn Error + Send + Sync> },
+    #[error("failed to read {}: {source}", path.display())]
+    Unreadable { path: PathBuf, source: io::Error },
+    #[error("failed to write {}: {source}", path.display())]
+    Unwritable { path: PathBuf, source: io::Error },
+    #[error("day {day} reads its input from stdin, so there's nowhere to put it")]
+    Stdin { day: u8 },
+}
+
+// whatever actually talks to the website, so the tests (or anyone with opinions about
+// HTTP clients) can swap it out
+pub trait Transport {
+    fn get(&self, url: &str, session: &str) -> Result<String, Box<dyn Error + Send + Sync>>;
+}
+
+pub struct Http {
+    agent: ureq::Agent,
+}
+
+impl Default for Http {
+    fn default() -> Self {
+        Http { agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build() }
+    }
+}
+
+impl Transport for Http {
+    // anything but a 2xx (like the 400 for a stale session) comes back as an error
+    fn get(&self, url: &str, session: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let response = self.agent.get(url)
+            .set("Cookie", &format!("session={}", session))
+            .call()?;
+        Ok(response.into_string()?)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Installed {
+    // there was already an input where the loaders look, so we left it alone
+    AlreadyThere,
+    FromCache,
+    Fetched,
+}
+
+pub struct InputCache<T> {
+    dir: PathBuf,
+    base_url: String,
+    session: Option<String>,
+    transport: T,
+}
+
+pub fn default_cache_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(CACHE_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .unwrap_or_else(|| input::workspace_root().join(".cache"))
+        .join("aoc")
+}
+
+impl InputCache<Http> {
+    pub fn from_env() -> Self {
+        let session = env::var(SESSION_VAR).ok().map(|s| s.trim().to_owned()).filter(|s| !s.is_empty());
+        InputCache::new(default_cache_dir(), BASE_URL, session, Http::default())
+    }
+}
+
+impl<T: Transport> InputCache<T> {
+    pub fn new(dir: impl Into<PathBuf>, base_url: &str, session: Option<String>, transport: T) -> Self {
+        InputCache { dir: dir.into(), base_url: base_url.trim_end_matches('/').to_owned(), session, transport }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(YEAR.to_string()).join(format!("day{:02}.txt", day))
+    }
+
+    // the cached input, going to the website for it only if it isn't cached yet. once it's
+    // cached it's never fetched again: the inputs don't change, and the site would rather
+    // we didn't ask
+    pub fn get(&self, day: u8) -> Result<(String, bool), FetchError> {
+        let path = self.path(day);
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok((input, false)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(source) => return Err(FetchError::Unreadable { path, source }),
+        }
+
+        let session = self.session.as_deref().ok_or(FetchError::NoSession { day })?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let input = self.transport.get(&url, session).map_err(|source| FetchError::Http { url, source })?;
+        write(&path, &input)?;
+        Ok((input, true))
+    }
+
+    // makes sure there's an input where `aoc run` and the days' own mains will look for it
+    // (dayNN/input.txt, or under $AOC_INPUT_DIR)
+    pub fn install(&self, day: u8) -> Result<Installed, FetchError> {
+        match input::resolve(day, None) {
+            Source::File(target) => self.install_to(day, &target),
+            Source::Stdin => Err(FetchError::Stdin { day }),
+        }
+    }
+
+    pub fn install_to(&self, day: u8, target: &Path) -> Result<Installed, FetchError> {
+        if target.exists() {
+            return Ok(Installed::AlreadyThere);
+        }
+
+        let (input, fetched) = self.get(day)?;
+        write(target, &input)?;
+        Ok(if fetched { Installed::Fetched } else { Installed::FromCache })
+    }
+}
+
+// via a temporary file, so that a half-written input never ends up looking cached
+fn write(path: &Path, contents: &str) -> Result<(), FetchError> {
+    let unwritable = |source| FetchError::Unwritable { path: path.to_owned(), source };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(unwritable)?;
+    }
+
+    let partial = path.with_extension("partial");
+    fs::write(&partial, contents).map_err(unwritable)?;
+    fs::rename(&partial, path).map_err(unwritable)
+}
+
+// a one-trick HTTP server on localhost: answers every request with `status` and `body`, and
+// sends each request it got (request line and headers) back down the channel
+#[cfg(test)]
+fn stub_server(status: &'static str, body: &'static str) -> (String, std::sync::mpsc::Receiver<String>) {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (sender, requests) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(&stream);
+            while reader.read_line(&mut request).unwrap() > 0 && !request.ends_with("\r\n\r\n") {}
+
+            write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+            if sender.send(request).is_err() {
+                break;
+            }
+        }
+    });
+
+    (url, requests)
+}
+
+#[cfg(test)]
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+pub fn test_fetch_once() {
+    let (url, requests) = stub_server("200 OK", "199\n200\n208\n");
+    let dir = scratch_dir("once");
+    let cache = InputCache::new(dir.join("cache"), &url, Some("53cr3t".to_owned()), Http::default());
+    let target = dir.join("day01").join("input.txt");
+
+    assert_eq!(cache.install_to(1, &target).unwrap(), Installed::Fetched);
+    assert_eq!(fs::read_to_string(&target).unwrap(), "199\n200\n208\n");
+    assert_eq!(fs::read_to_string(dir.join("cache/2021/day01.txt")).unwrap(), "199\n200\n208\n");
+
+    let request = requests.recv().unwrap();
+    assert!(request.starts_with("GET /2021/day/1/input HTTP/1.1\r\n"));
+    assert!(request.contains("session=53cr3t"));
+
+    // once it's in place, or even just cached, there's no need to ask again
+    assert_eq!(cache.install_to(1, &target).unwrap(), Installed::AlreadyThere);
+    fs::remove_file(&target).unwrap();
+    assert_eq!(cache.install_to(1, &target).unwrap(), Installed::FromCache);
+    assert_eq!(fs::read_to_string(&target).unwrap(), "199\n200\n208\n");
+    assert!(requests.try_recv().is_err());
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+pub fn test_fetch_failures() {
+    let (url, requests) = stub_server("400 Bad Request", "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n");
+    let dir = scratch_dir("failures");
+    let target = dir.join("input.txt");
+
+    let cache = InputCache::new(dir.join("cache"), &url, None, Http::default());
+    assert!(matches!(cache.install_to(4, &target), Err(FetchError::NoSession { day: 4 })));
+
+    let cache = InputCache::new(dir.join("cache"), &url, Some("stale".to_owned()), Http::default());
+    assert!(matches!(cache.install_to(4, &target), Err(FetchError::Http { .. })));
+    assert!(requests.recv().unwrap().starts_with("GET /2021/day/4/input "));
+
+    // and nothing's been left behind to look like a real input
+    assert!(!target.exists());
+    assert!(!cache.path(4).exists());
+
+    let _ = fs::remove_dir_all(dir);
+}
@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::answers::{Answers, Tally, Verdict};
+use crate::days::{Report, Timed};
+use crate::profile::Usage;
+
+// the machine-readable version of what `run` prints, one entry per day:
+//
+//   {"days": [{"day": 1, "error": null,
+//              "parse": {"elapsed_ns": 51200, "error": null},
+//              "parts": [{"part": 1, "answer": 1451, "elapsed_ns": 3100, "error": null}, ...]}]}
+//
+// every field is always there (as null if need be) so scripts don't have to guess, apart
+// from each part's `check` and the overall `summary`, which only show up with --check, and
+// the parse's and each part's `memory`, which only show up with --profile
+#[derive(Serialize)]
+pub struct Document {
+    pub days: Vec<Day>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<Tally>,
+}
+
+#[derive(Serialize)]
+pub struct Day {
+    pub day: u8,
+    // the input couldn't be found, or the day isn't solved yet
+    pub error: Option<String>,
+    pub parse: Option<Parse>,
+    pub parts: Vec<Part>,
+}
+
+#[derive(Serialize)]
+pub struct Parse {
+    pub elapsed_ns: u64,
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Usage>,
+}
+
+#[derive(Serialize)]
+pub struct Part {
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub elapsed_ns: u64,
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check: Option<Check>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Usage>,
+}
+
+// numbers stay numbers, and anything else (like the day 13 picture) is a string
+#[derive(Serialize, PartialEq, Eq, Debug)]
+#[serde(untagged)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+#[derive(Serialize)]
+pub struct Check {
+    pub verdict: &'static str,
+    pub expected: Option<String>,
+}
+
+impl Answer {
+    pub fn new(answer: &str) -> Answer {
+        if let Ok(n) = answer.parse() {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = answer.parse() {
+            Answer::Signed(n)
+        } else {
+            Answer::Text(answer.trim_end().to_owned())
+        }
+    }
+}
+
+impl Check {
+    pub fn new(verdict: Verdict) -> Check {
+        match verdict {
+            Verdict::Pass => Check { verdict: "pass", expected: None },
+            Verdict::Fail { expected } => Check { verdict: "fail", expected: Some(expected) },
+            Verdict::Missing => Check { verdict: "missing", expected: None },
+        }
+    }
+}
+
+fn nanos(elapsed: Duration) -> u64 {
+    elapsed.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+impl Day {
+    pub fn failed(day: u8, error: String) -> Day {
+        Day { day, error: Some(error), parse: None, parts: Vec::new() }
+    }
+
+    pub fn new(report: &Report, answers: Option<&Answers>) -> Day {
+        let parts = report.parts.iter()
+            .map(|(part, timed)| Part::new(report.day, *part, timed, answers))
+            .collect();
+
+        Day {
+            day: report.day,
+            error: None,
+            parse: Some(Parse {
+                elapsed_ns: nanos(report.parse.elapsed),
+                error: report.parse.result.clone().err(),
+                memory: report.parse.usage,
+            }),
+            parts,
+        }
+    }
+}
+
+impl Part {
+    fn new(day: u8, part: u8, timed: &Timed<String>, answers: Option<&Answers>) -> Part {
+        Part {
+            part,
+            answer: timed.result.as_deref().ok().map(Answer::new),
+            elapsed_ns: nanos(timed.elapsed),
+            error: timed.result.clone().err(),
+            check: answers.map(|answers| Check::new(answers.judge(day, part, &timed.result))),
+            memory: timed.usage,
+        }
+    }
+}
+
+#[test]
+pub fn test_answer() {
+    assert_eq!(Answer::new("1451"), Answer::Unsigned(1451));
+    assert_eq!(Answer::new("-12"), Answer::Signed(-12));
+    assert_eq!(Answer::new("#.#\n.#.\n"), Answer::Text("#.#\n.#.".to_owned()));
+    assert_eq!(serde_json::to_string(&Answer::new("26984457539")).unwrap(), "26984457539");
+}
@@ -0,0 +1,354 @@
+mod answers;
+mod days;
+mod examples;
+mod fetch;
+mod json;
+mod profile;
+mod tui;
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use answers::{Answers, Tally, Verdict};
+use days::{Report, Timed};
+use fetch::{InputCache, Installed};
+
+const LAST_DAY: u8 = 20;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or `all` of them
+    Run {
+        /// Which day to run (1-25), or `all`
+        day: Selection,
+        /// Only run this part of the puzzle
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from this file (or `-` for stdin) instead of the default location
+        #[arg(long)]
+        input: Option<String>,
+        /// Compare the answers against the known-good ones instead of just printing them
+        #[arg(long)]
+        check: bool,
+        /// Where to find the known-good answers for --check (defaults to answers.toml)
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Print the results as human-readable text, or as one JSON document
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Also draw a picture of the day (5, 9, 11, 13 or 15) into this file: .pbm, .pgm, .ppm,
+        /// or .png with the `png` feature
+        #[arg(long)]
+        image: Option<PathBuf>,
+        /// Make each cell of the --image this many pixels across
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        scale: u16,
+        /// Print every step of the simulations (days 4, 6, 11, 13, 14 and 18) to stderr as they run
+        #[arg(long)]
+        trace: bool,
+        /// Also report how much memory each parse and part allocated (needs the `profile` feature)
+        #[arg(long)]
+        profile: bool,
+    },
+    /// Run each day's examples (dayNN/fixtures/*.txt) and check them against the answers
+    /// in dayNN/fixtures/answers.toml
+    Examples {
+        /// Which day's examples to run (1-25), or `all`
+        day: Selection,
+    },
+    /// Put each day's puzzle input where `run` looks for it, downloading any that aren't
+    /// cached yet (which needs AOC_SESSION set to your session cookie)
+    Fetch {
+        /// Which day to fetch (1-25), or `all`
+        day: Selection,
+    },
+    /// Browse the days, their answers and timings in the terminal, and step back and forth
+    /// through the simulations (days 11, 13, 14 and 18)
+    Tui,
+    /// Print a made-up input for a day, for benchmarking or testing
+    Generate {
+        /// Which day to generate an input for
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Roughly how big to make it: lines, boards, scanners, etc. depending on the day
+        #[arg(long)]
+        size: Option<usize>,
+        /// The same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+impl Selection {
+    fn days(self) -> Vec<u8> {
+        match self {
+            Selection::All => (1..=LAST_DAY).collect(),
+            Selection::Day(day) => vec![day],
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+
+        match s.parse() {
+            Ok(day @ 1..=25) => Ok(Selection::Day(day)),
+            _ => Err(format!("expected a day between 1 and 25, or `all`, but got `{}`", s)),
+        }
+    }
+}
+
+// pictures (and parse errors) get their own lines so they still line up
+fn show(answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("\n{}", answer.trim_end())
+    } else {
+        answer.to_owned()
+    }
+}
+
+// how long it took, and with --profile how much memory it went through
+fn cost<T>(timed: &Timed<T>) -> String {
+    match &timed.usage {
+        Some(usage) => format!("{:?}; {}", timed.elapsed, usage),
+        None => format!("{:?}", timed.elapsed),
+    }
+}
+
+fn print_report(report: &Report) {
+    match &report.parse.result {
+        Ok(()) => println!("Day {:02} (parsed in {})", report.day, cost(&report.parse)),
+        Err(e) => {
+            println!("Day {:02}: failed to parse input: {}", report.day, show(e));
+            return;
+        }
+    }
+
+    for (part, timed) in &report.parts {
+        match &timed.result {
+            // some answers (day 13) are pictures rather than numbers
+            Ok(answer) if answer.contains('\n') => {
+                println!("  Part {} ({}):", part, cost(timed));
+                println!("{}", answer.trim_end());
+            }
+            Ok(answer) => println!("  Part {}: {} ({})", part, answer, cost(timed)),
+            Err(e) => println!("  Part {}: {} ({})", part, e, cost(timed)),
+        }
+    }
+}
+
+fn print_check(report: &Report, answers: &Answers, tally: &mut Tally) {
+    if let Err(e) = &report.parse.result {
+        println!("Day {:02}: FAIL - failed to parse input ({:?}): {}", report.day, report.parse.elapsed, show(e));
+        tally.failed += 1;
+        return;
+    }
+
+    for (part, timed) in &report.parts {
+        let label = format!("Day {:02} part {}", report.day, part);
+        let verdict = answers.judge(report.day, *part, &timed.result);
+        match (&timed.result, &verdict) {
+            (Ok(_), Verdict::Pass) => println!("{}: pass ({:?})", label, timed.elapsed),
+            (Ok(actual), Verdict::Fail { expected }) =>
+                println!("{}: FAIL - expected {}, got {} ({:?})", label, show(expected), show(actual), timed.elapsed),
+            (Ok(actual), Verdict::Missing) => println!("{}: missing - got {} ({:?})", label, show(actual), timed.elapsed),
+            (Err(e), Verdict::Missing) => println!("{}: missing - {} ({:?})", label, e, timed.elapsed),
+            (Err(e), _) => println!("{}: FAIL - {} ({:?})", label, e, timed.elapsed),
+        }
+        tally.record(&verdict);
+    }
+}
+
+// same as print_check, but tallying without printing anything, for --format json
+fn tally_check(report: &Report, answers: &Answers, tally: &mut Tally) {
+    if report.parse.result.is_err() {
+        tally.failed += 1;
+        return;
+    }
+
+    for (part, timed) in &report.parts {
+        tally.record(&answers.judge(report.day, *part, &timed.result));
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run { day, part, input, check, answers, format, image, scale, trace, profile } => {
+            if input.is_some() && matches!(day, Selection::All) {
+                eprintln!("--input only makes sense when running a single day");
+                std::process::exit(2);
+            }
+            if image.is_some() && matches!(day, Selection::All) {
+                eprintln!("--image only makes sense when running a single day");
+                std::process::exit(2);
+            }
+            if profile && !profile::AVAILABLE {
+                eprintln!("--profile needs the `profile` feature (cargo run --features profile)");
+                std::process::exit(2);
+            }
+            if profile {
+                profile::enable();
+            }
+
+            let answers = if check {
+                let path = answers.unwrap_or_else(answers::default_path);
+                match Answers::load(&path) {
+                    Ok(answers) => Some(answers),
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        std::process::exit(2);
+                    }
+                }
+            } else {
+                None
+            };
+
+            let mut tally = Tally::default();
+            let mut no_picture = false;
+            let mut document = json::Document { days: Vec::new(), summary: None };
+            for (day, report) in days::run_all(&day.days(), input.as_deref(), part, trace, image.is_some()) {
+                let report = match report {
+                    Ok(report) => report,
+                    Err(e) => {
+                        match format {
+                            Format::Text => println!("Day {:02}: {}", day, e),
+                            Format::Json => document.days.push(json::Day::failed(day, e.to_string())),
+                        }
+                        tally.skipped += 1;
+                        continue;
+                    }
+                };
+
+                // on stderr, so as not to get in the way of --format json
+                if let (Some(report), Some(path)) = (&report, &image) {
+                    match &report.picture {
+                        Some(Ok(picture)) => match picture.scaled(scale as usize).save(path) {
+                            Ok(()) => eprintln!("Day {:02}: picture saved to {}", day, path.display()),
+                            Err(e) => {
+                                eprintln!("Day {:02}: {}", day, e);
+                                no_picture = true;
+                            }
+                        },
+                        Some(Err(e)) => {
+                            eprintln!("Day {:02}: failed to draw a picture: {}", day, e);
+                            no_picture = true;
+                        }
+                        None => {
+                            eprintln!("Day {:02}: doesn't have a picture to draw", day);
+                            no_picture = true;
+                        }
+                    }
+                }
+
+                match (report, format) {
+                    (Some(report), Format::Text) => match &answers {
+                        Some(answers) => print_check(&report, answers, &mut tally),
+                        None => print_report(&report),
+                    },
+                    (Some(report), Format::Json) => {
+                        if let Some(answers) = &answers {
+                            tally_check(&report, answers, &mut tally);
+                        }
+                        document.days.push(json::Day::new(&report, answers.as_ref()));
+                    }
+                    (None, Format::Text) => println!("Day {:02}: not solved yet", day),
+                    (None, Format::Json) => document.days.push(json::Day::failed(day, "not solved yet".to_owned())),
+                }
+            }
+
+            let failed = (answers.is_some() && tally.failed > 0) || no_picture;
+            match format {
+                Format::Text if answers.is_some() => {
+                    println!();
+                    println!("{} passed, {} failed, {} missing, {} days skipped",
+                        tally.passed, tally.failed, tally.missing, tally.skipped);
+                }
+                Format::Text => {}
+                Format::Json => {
+                    document.summary = answers.as_ref().map(|_| tally);
+                    println!("{}", serde_json::to_string_pretty(&document).expect("results always serialize"));
+                }
+            }
+
+            if failed {
+                std::process::exit(1);
+            }
+        }
+        Command::Examples { day } => {
+            let mut failed = false;
+            for day in day.days() {
+                match examples::check(day) {
+                    (0, problems) if problems.is_empty() => println!("Day {:02}: no examples", day),
+                    (count, problems) if problems.is_empty() => println!("Day {:02}: {} example{} pass", day, count, if count == 1 { "" } else { "s" }),
+                    (_, problems) => {
+                        problems.iter().for_each(|problem| println!("{}", problem));
+                        failed = true;
+                    }
+                }
+            }
+
+            if failed {
+                std::process::exit(1);
+            }
+        }
+        Command::Fetch { day } => {
+            let cache = InputCache::from_env();
+            let mut failed = false;
+            for day in day.days() {
+                match cache.install(day) {
+                    Ok(Installed::AlreadyThere) => println!("Day {:02}: already in place", day),
+                    Ok(Installed::FromCache) => println!("Day {:02}: copied from {}", day, cache.path(day).display()),
+                    Ok(Installed::Fetched) => println!("Day {:02}: fetched", day),
+                    Err(e) => {
+                        println!("Day {:02}: {}", day, e);
+                        failed = true;
+                    }
+                }
+            }
+
+            if failed {
+                std::process::exit(1);
+            }
+        }
+        Command::Tui => {
+            if let Err(e) = tui::run(LAST_DAY) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Generate { day, size, seed } => match days::generate(day, size, seed) {
+            Some(input) => println!("{}", input),
+            None => {
+                eprintln!("Day {:02}: not solved yet", day);
+                std::process::exit(2);
+            }
+        },
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+
+// how much memory a parse or a part went through, for `run --profile`. counting needs every
+// allocation to go through our own allocator, which costs a little on every one of them, so
+// it's only there with the `profile` feature
+#[derive(Serialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Usage {
+    pub allocations: u64,
+    // everything asked for, even if it was given straight back
+    pub allocated_bytes: u64,
+    // the most that was in use at once, over and above whatever was in use beforehand
+    pub peak_bytes: u64,
+}
+
+pub const AVAILABLE: bool = cfg!(feature = "profile");
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    AVAILABLE && ENABLED.load(Ordering::Relaxed)
+}
+
+#[cfg(feature = "profile")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+    pub static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    pub static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+    pub static CURRENT: AtomicUsize = AtomicUsize::new(0);
+    pub static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+    pub struct Counting;
+
+    fn allocated(size: usize) {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        ALLOCATED.fetch_add(size, Relaxed);
+        let current = CURRENT.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(current, Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            CURRENT.fetch_sub(layout.size(), Relaxed);
+        }
+
+        // a Vec growing counts as a new allocation of the new size, which is what it usually is
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = System.realloc(ptr, layout, new_size);
+            if !new.is_null() {
+                CURRENT.fetch_sub(layout.size(), Relaxed);
+                allocated(new_size);
+            }
+            new
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+}
+
+// runs `f`, and if profiling's enabled says how much it allocated. the counts are for the
+// whole process, so anything else going on at the same time gets counted too: `run_all`
+// runs the days one at a time while profiling for just that reason
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !enabled() {
+        return (f(), None);
+    }
+
+    counted(f)
+}
+
+#[cfg(feature = "profile")]
+fn counted<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    use counting::{ALLOCATED, ALLOCATIONS, CURRENT, PEAK};
+    use std::sync::atomic::Ordering::Relaxed;
+
+    let (allocations, allocated) = (ALLOCATIONS.load(Relaxed), ALLOCATED.load(Relaxed));
+    let baseline = CURRENT.load(Relaxed);
+    PEAK.store(baseline, Relaxed);
+    let result = f();
+    let usage = Usage {
+        allocations: (ALLOCATIONS.load(Relaxed) - allocations) as u64,
+        allocated_bytes: (ALLOCATED.load(Relaxed) - allocated) as u64,
+        peak_bytes: PEAK.load(Relaxed).saturating_sub(baseline) as u64,
+    };
+
+    (result, Some(usage))
+}
+
+#[cfg(not(feature = "profile"))]
+fn counted<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    (f(), None)
+}
+
+// in whichever unit keeps the number readable
+pub struct Bytes(pub u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{:.1} {}", size, UNITS[unit])
+    }
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} allocation{}, {} in all, {} at peak",
+            self.allocations, if self.allocations == 1 { "" } else { "s" },
+            Bytes(self.allocated_bytes), Bytes(self.peak_bytes))
+    }
+}
+
+#[test]
+pub fn test_usage() {
+    assert_eq!(Bytes(1000).to_string(), "1000 B");
+    assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+    assert_eq!(Bytes(3 << 30).to_string(), "3.0 GiB");
+    let usage = Usage { allocations: 1, allocated_bytes: 4096, peak_bytes: 2048 };
+    assert_eq!(usage.to_string(), "1 allocation, 4.0 KiB in all, 2.0 KiB at peak");
+
+    enable();
+    let (v, usage) = measure(|| {
+        let mut v = vec![0u8; 64 * 1024];
+        v.extend([1, 2, 3]);
+        v.len()
+    });
+    assert_eq!(v, 64 * 1024 + 3);
+    if AVAILABLE {
+        // other tests are running at the same time, so these are only at least (and there's
+        // no telling what the peak was)
+        let usage = usage.unwrap();
+        assert!(usage.allocations >= 2);
+        assert!(usage.allocated_bytes >= 128 * 1024);
+    } else {
+        assert_eq!(usage, None);
+    }
+}
@@ -0,0 +1,290 @@
+use std::io;
+use std::time::Duration;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+
+use common::input::{self, Source};
+use common::trace::Step;
+
+use crate::days::{self, Report};
+
+// `aoc tui`: every day in a list with its last answers, and the simulations to step through
+//
+//   up/down      pick a day              left/right   back/forward a step
+//   enter        run it                  pgup/pgdn    ten steps at a time
+//   a            run them all            home/end     the first/last step
+//   i            load an input file      j/k          scroll the state
+//   s            step through it         esc          back to the list
+//   q            quit
+
+const HELP: &str = "enter: run  a: run all  i: input file  s: step through  q: quit";
+const SIMULATION_HELP: &str = "left/right: step  pgup/pgdn: 10 steps  home/end: first/last  j/k: scroll  esc: back";
+
+struct DayRow {
+    day: u8,
+    // from `i`, instead of wherever `aoc run` would look
+    input: Option<String>,
+    report: Option<Result<Report, String>>,
+}
+
+struct Simulation {
+    day: u8,
+    steps: Vec<Step>,
+    position: usize,
+    scroll: u16,
+}
+
+enum Mode {
+    Browse,
+    // typing in the path of an input file for the selected day
+    Prompt(String),
+    Simulate(Simulation),
+}
+
+pub struct App {
+    rows: Vec<DayRow>,
+    table: TableState,
+    mode: Mode,
+    message: Option<String>,
+    quit: bool,
+}
+
+impl App {
+    pub fn new(last_day: u8) -> App {
+        App {
+            rows: (1..=last_day).map(|day| DayRow { day, input: None, report: None }).collect(),
+            table: TableState::default().with_selected(0),
+            mode: Mode::Browse,
+            message: None,
+            quit: false,
+        }
+    }
+
+    fn selected(&self) -> usize {
+        self.table.selected().unwrap_or(0)
+    }
+
+    fn load(&self, i: usize) -> Result<String, String> {
+        let row = &self.rows[i];
+        input::load(row.day, row.input.as_deref()).map_err(|e| e.to_string())
+    }
+
+    fn run(&mut self, i: usize) {
+        let report = self.load(i).and_then(|input| {
+            days::run(self.rows[i].day, &input, None).ok_or_else(|| "not solved yet".to_owned())
+        });
+        self.rows[i].report = Some(report);
+    }
+
+    fn simulate(&mut self, i: usize) {
+        let day = self.rows[i].day;
+        let steps = match self.load(i).map(|input| days::simulate(day, &input)) {
+            Ok(Some(steps)) => steps,
+            Ok(None) => Err(format!("day {} isn't a simulation (try 11, 13, 14 or 18)", day)),
+            Err(e) => Err(e),
+        };
+
+        match steps {
+            Ok(steps) if steps.is_empty() => self.message = Some(format!("day {} didn't take any steps", day)),
+            Ok(steps) => self.mode = Mode::Simulate(Simulation { day, steps, position: 0, scroll: 0 }),
+            Err(e) => self.message = Some(e),
+        }
+    }
+
+    pub fn handle(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+
+        let selected = self.selected();
+        match &mut self.mode {
+            Mode::Browse => {
+                self.message = None;
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+                    KeyCode::Up => self.table.select(Some(selected.saturating_sub(1))),
+                    KeyCode::Down => self.table.select(Some((selected + 1).min(self.rows.len() - 1))),
+                    KeyCode::Enter | KeyCode::Char('r') => self.run(selected),
+                    KeyCode::Char('a') => (0..self.rows.len()).for_each(|i| self.run(i)),
+                    KeyCode::Char('i') => self.mode = Mode::Prompt(self.rows[selected].input.clone().unwrap_or_default()),
+                    KeyCode::Char('s') => self.simulate(selected),
+                    _ => {}
+                }
+            }
+            Mode::Prompt(path) => match key.code {
+                KeyCode::Char(c) => path.push(c),
+                KeyCode::Backspace => {
+                    path.pop();
+                }
+                KeyCode::Enter => {
+                    // an empty path goes back to the default
+                    let path = Some(path.trim().to_owned()).filter(|p| !p.is_empty());
+                    let row = &mut self.rows[selected];
+                    row.input = path;
+                    row.report = None;
+                    self.mode = Mode::Browse;
+                }
+                KeyCode::Esc => self.mode = Mode::Browse,
+                _ => {}
+            },
+            Mode::Simulate(simulation) => {
+                let last = simulation.steps.len() - 1;
+                let position = match key.code {
+                    KeyCode::Left => simulation.position.saturating_sub(1),
+                    KeyCode::Right => (simulation.position + 1).min(last),
+                    KeyCode::PageUp => simulation.position.saturating_sub(10),
+                    KeyCode::PageDown => (simulation.position + 10).min(last),
+                    KeyCode::Home => 0,
+                    KeyCode::End => last,
+                    KeyCode::Char('k') | KeyCode::Up => {
+                        simulation.scroll = simulation.scroll.saturating_sub(1);
+                        simulation.position
+                    }
+                    KeyCode::Char('j') | KeyCode::Down => {
+                        simulation.scroll = simulation.scroll.saturating_add(1);
+                        simulation.position
+                    }
+                    KeyCode::Char('q') | KeyCode::Esc => {
+                        self.mode = Mode::Browse;
+                        return;
+                    }
+                    _ => simulation.position,
+                };
+                simulation.position = position;
+            }
+        }
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame) {
+        let [main, status] = Layout::vertical([Constraint::Min(3), Constraint::Length(1)]).areas(frame.area());
+        let status_line = match &self.mode {
+            Mode::Browse => self.message.clone().unwrap_or_else(|| HELP.to_owned()),
+            Mode::Prompt(path) => format!("input file for day {} (empty for the default): {}_", self.rows[self.selected()].day, path),
+            Mode::Simulate(_) => SIMULATION_HELP.to_owned(),
+        };
+        frame.render_widget(Paragraph::new(status_line), status);
+
+        if let Mode::Simulate(simulation) = &self.mode {
+            let step = &simulation.steps[simulation.position];
+            let title = format!(" Day {:02}: {} ({}/{}) ", simulation.day, step.step, simulation.position + 1, simulation.steps.len());
+            let state = Paragraph::new(step.state.trim_end())
+                .block(Block::bordered().title(title))
+                .scroll((simulation.scroll, 0));
+            frame.render_widget(state, main);
+            return;
+        }
+
+        let rows = self.rows.iter().map(|row| {
+            let source = match input::resolve(row.day, row.input.as_deref()) {
+                Source::File(path) => path.display().to_string(),
+                Source::Stdin => "stdin".to_owned(),
+            };
+            let mut cells = vec![format!("{:02}", row.day)];
+            cells.extend(summarise(&row.report));
+            cells.push(source);
+            Row::new(cells.into_iter().map(Cell::from))
+        });
+
+        let widths = [Constraint::Length(4), Constraint::Fill(1), Constraint::Fill(1), Constraint::Length(12), Constraint::Fill(2)];
+        let table = Table::new(rows, widths)
+            .header(Row::new(["Day", "Part 1", "Part 2", "Time", "Input"]).style(Style::new().add_modifier(Modifier::BOLD)))
+            .block(Block::bordered().title(Line::from(" Advent of Code 2021 ")))
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(table, main, &mut self.table);
+    }
+}
+
+// the answer to each part, and how long the whole day took. pictures (day 13) don't fit in a
+// table cell, so they just get their first line
+fn summarise(report: &Option<Result<Report, String>>) -> [String; 3] {
+    let report = match report {
+        None => return [String::new(), String::new(), String::new()],
+        Some(Err(e)) => return [e.clone(), String::new(), String::new()],
+        Some(Ok(report)) => report,
+    };
+    if let Err(e) = &report.parse.result {
+        return [e.lines().next().unwrap_or_default().to_owned(), String::new(), format!("{:.2?}", report.parse.elapsed)];
+    }
+
+    let answer = |part| report.parts.iter()
+        .find(|(p, _)| *p == part)
+        .map(|(_, timed)| match &timed.result {
+            Ok(answer) if answer.contains('\n') => format!("{}...", answer.lines().next().unwrap_or_default()),
+            Ok(answer) => answer.clone(),
+            Err(e) => e.clone(),
+        })
+        .unwrap_or_default();
+    let elapsed = report.parse.elapsed + report.parts.iter().map(|(_, timed)| timed.elapsed).sum::<Duration>();
+    [answer(1), answer(2), format!("{:.2?}", elapsed)]
+}
+
+pub fn run(last_day: u8) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, App::new(last_day));
+    ratatui::restore();
+    result
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, mut app: App) -> io::Result<()> {
+    while !app.quit {
+        terminal.draw(|frame| app.draw(frame))?;
+        if let Event::Key(key) = event::read()? {
+            app.handle(key);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+pub fn test_app() {
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    let mut app = App::new(20);
+    let press = |app: &mut App, code| app.handle(KeyEvent::from(code));
+
+    for _ in 0..12 {
+        press(&mut app, KeyCode::Down);
+    }
+    assert_eq!(app.selected(), 12);
+
+    let example = input::fixture_path(13, "example.txt").display().to_string();
+    press(&mut app, KeyCode::Char('i'));
+    example.chars().for_each(|c| press(&mut app, KeyCode::Char(c)));
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.rows[12].input.as_deref(), Some(example.as_str()));
+
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(summarise(&app.rows[12].report)[0], "17");
+
+    // the example folds twice, and stepping stops at either end
+    press(&mut app, KeyCode::Char('s'));
+    press(&mut app, KeyCode::End);
+    press(&mut app, KeyCode::Right);
+    match &app.mode {
+        Mode::Simulate(simulation) => assert_eq!((simulation.steps.len(), simulation.position), (2, 1)),
+        _ => panic!("should be stepping through day 13"),
+    }
+
+    let mut terminal = Terminal::new(TestBackend::new(60, 12)).unwrap();
+    terminal.draw(|frame| app.draw(frame)).unwrap();
+    let screen = format!("{:?}", terminal.backend().buffer());
+    assert!(screen.contains("Day 13: fold along x=5 (16 dots) (2/2)"));
+    assert!(screen.contains("#####"));
+
+    // and day 12 has nothing to step through
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Char('i'));
+    input::fixture_path(12, "example.txt").display().to_string().chars().for_each(|c| press(&mut app, KeyCode::Char(c)));
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Char('s'));
+    assert!(matches!(app.mode, Mode::Browse));
+    assert_eq!(app.message.as_deref(), Some("day 12 isn't a simulation (try 11, 13, 14 or 18)"));
+}
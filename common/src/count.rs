@@ -0,0 +1,121 @@
+use std::fmt;
+
+use thiserror::Error;
+
+#[derive(Error, Clone, Copy, PartialEq, Eq, Debug)]
+#[error("the answer doesn't fit in a u64 (build with the `bignum` feature to get it anyway)")]
+pub struct Overflow;
+
+// whether this build has the `bignum` feature, so counts never overflow. it's all or nothing:
+// turning it on for any day turns it on for all of them
+pub const UNBOUNDED: bool = cfg!(feature = "bignum");
+
+#[cfg(not(feature = "bignum"))]
+type Inner = u64;
+#[cfg(feature = "bignum")]
+type Inner = num_bigint::BigUint;
+
+// a count of things that can grow exponentially, like day 6's lanternfish or day 14's polymer.
+// normally it's a u64 that notices when it overflows, and with the `bignum` feature it's as
+// big as it needs to be. all the arithmetic is checked either way, so the days don't need
+// to care which they've got
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Count(Inner);
+
+impl Count {
+    pub fn zero() -> Count {
+        Count::from(0u64)
+    }
+
+    pub fn one() -> Count {
+        Count::from(1u64)
+    }
+
+    #[cfg(not(feature = "bignum"))]
+    pub fn checked_add(&self, other: &Count) -> Result<Count, Overflow> {
+        self.0.checked_add(other.0).map(Count).ok_or(Overflow)
+    }
+
+    #[cfg(feature = "bignum")]
+    pub fn checked_add(&self, other: &Count) -> Result<Count, Overflow> {
+        Ok(Count(&self.0 + &other.0))
+    }
+
+    #[cfg(not(feature = "bignum"))]
+    pub fn checked_mul(&self, other: &Count) -> Result<Count, Overflow> {
+        self.0.checked_mul(other.0).map(Count).ok_or(Overflow)
+    }
+
+    #[cfg(feature = "bignum")]
+    pub fn checked_mul(&self, other: &Count) -> Result<Count, Overflow> {
+        Ok(Count(&self.0 * &other.0))
+    }
+
+    // counts can't go below zero, so this is the same kind of mistake as overflowing
+    #[cfg(not(feature = "bignum"))]
+    pub fn checked_sub(&self, other: &Count) -> Result<Count, Overflow> {
+        self.0.checked_sub(other.0).map(Count).ok_or(Overflow)
+    }
+
+    #[cfg(feature = "bignum")]
+    pub fn checked_sub(&self, other: &Count) -> Result<Count, Overflow> {
+        if other > self {
+            return Err(Overflow);
+        }
+
+        Ok(Count(&self.0 - &other.0))
+    }
+
+    pub fn sum<'a>(counts: impl IntoIterator<Item = &'a Count>) -> Result<Count, Overflow> {
+        counts.into_iter().try_fold(Count::zero(), |total, c| total.checked_add(c))
+    }
+
+    pub fn product<'a>(counts: impl IntoIterator<Item = &'a Count>) -> Result<Count, Overflow> {
+        counts.into_iter().try_fold(Count::one(), |total, c| total.checked_mul(c))
+    }
+
+    // how many `count`s add up to: for counting things that come in batches
+    pub fn times(&self, n: u64) -> Result<Count, Overflow> {
+        self.checked_mul(&Count::from(n))
+    }
+}
+
+impl From<u64> for Count {
+    #[cfg(not(feature = "bignum"))]
+    fn from(n: u64) -> Self {
+        Count(n)
+    }
+
+    #[cfg(feature = "bignum")]
+    fn from(n: u64) -> Self {
+        Count(n.into())
+    }
+}
+
+impl From<usize> for Count {
+    fn from(n: usize) -> Self {
+        Count::from(n as u64)
+    }
+}
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[test]
+pub fn test_overflow() {
+    let big = Count::from(u64::MAX);
+    assert_eq!(big.checked_add(&Count::zero()), Ok(big.clone()));
+    assert_eq!(Count::from(3u64).checked_sub(&Count::from(5u64)), Err(Overflow));
+    assert_eq!(Count::product(&[Count::from(6u64), Count::from(7u64)]), Ok(Count::from(42u64)));
+
+    if UNBOUNDED {
+        assert_eq!(big.checked_add(&Count::one()).unwrap().to_string(), "18446744073709551616");
+        assert_eq!(big.times(2).unwrap().to_string(), "36893488147419103230");
+    } else {
+        assert_eq!(big.checked_add(&Count::one()), Err(Overflow));
+        assert_eq!(big.times(2), Err(Overflow));
+    }
+}
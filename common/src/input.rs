@@ -0,0 +1,123 @@
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+// if this is set, inputs are looked up as $AOC_INPUT_DIR/dayNN.txt
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Error)]
+pub enum InputError {
+    #[error("no input for day {day}: {} does not exist (pass a path, `-` for stdin, set {}, or try `aoc fetch {day}`)", path.display(), INPUT_DIR_VAR)]
+    Missing { day: u8, path: PathBuf },
+    #[error("failed to read {}: {source}", path.display())]
+    Unreadable { path: PathBuf, source: io::Error },
+    #[error("failed to read input from stdin: {0}")]
+    Stdin(io::Error),
+}
+
+// `main` returning one of these prints it with Debug, so make that readable too
+impl fmt::Debug for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+// where the input lived before we stopped embedding it: dayNN/input.txt
+pub fn default_path(day: u8) -> PathBuf {
+    workspace_root()
+        .join(format!("day{:02}", day))
+        .join("input.txt")
+}
+
+// the puzzle examples that live alongside each day, e.g. day12/fixtures/example.txt
+pub fn fixture_path(day: u8, name: &str) -> PathBuf {
+    workspace_root()
+        .join(format!("day{:02}", day))
+        .join("fixtures")
+        .join(name)
+}
+
+pub fn resolve_with(day: u8, arg: Option<&str>, input_dir: Option<&Path>) -> Source {
+    match (arg, input_dir) {
+        (Some("-"), _) => Source::Stdin,
+        (Some(path), _) => Source::File(PathBuf::from(path)),
+        (None, Some(dir)) => Source::File(dir.join(format!("day{:02}.txt", day))),
+        (None, None) => Source::File(default_path(day)),
+    }
+}
+
+pub fn resolve(day: u8, arg: Option<&str>) -> Source {
+    let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+    resolve_with(day, arg, input_dir.as_deref())
+}
+
+fn file_error(day: u8, path: &Path, source: io::Error) -> InputError {
+    match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing { day, path: path.to_owned() },
+        _ => InputError::Unreadable { path: path.to_owned(), source },
+    }
+}
+
+pub fn read(day: u8, source: &Source) -> Result<String, InputError> {
+    match source {
+        Source::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).map_err(InputError::Stdin)?;
+            Ok(input)
+        }
+        Source::File(path) => std::fs::read_to_string(path).map_err(|source| file_error(day, path, source)),
+    }
+}
+
+// for inputs too big to read in all at once: just opens it, and the rest is up to the caller
+pub fn open(day: u8, source: &Source) -> Result<Box<dyn BufRead>, InputError> {
+    match source {
+        Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        Source::File(path) => match File::open(path) {
+            Ok(file) => Ok(Box::new(BufReader::new(file))),
+            Err(source) => Err(file_error(day, path, source)),
+        },
+    }
+}
+
+pub fn load(day: u8, arg: Option<&str>) -> Result<String, InputError> {
+    read(day, &resolve(day, arg))
+}
+
+// the first command line argument, if any, says where to read the input from
+pub fn from_args(day: u8) -> Result<String, InputError> {
+    load(day, env::args().nth(1).as_deref())
+}
+
+#[test]
+pub fn test_resolve() {
+    assert_eq!(resolve_with(3, Some("-"), None), Source::Stdin);
+    assert_eq!(resolve_with(3, Some("foo.txt"), Some(Path::new("inputs"))), Source::File(PathBuf::from("foo.txt")));
+    assert_eq!(resolve_with(3, None, Some(Path::new("inputs"))), Source::File(PathBuf::from("inputs/day03.txt")));
+    assert_eq!(resolve_with(3, None, None), Source::File(default_path(3)));
+    assert!(default_path(3).ends_with("day03/input.txt"));
+}
+
+#[test]
+pub fn test_missing() {
+    let path = PathBuf::from("definitely/not/here.txt");
+    let error = read(12, &Source::File(path)).unwrap_err();
+    assert!(matches!(error, InputError::Missing { day: 12, .. }));
+    assert!(error.to_string().contains("definitely/not/here.txt"));
+    let error = open(12, &Source::File(PathBuf::from("definitely/not/here.txt"))).err().unwrap();
+    assert!(matches!(error, InputError::Missing { day: 12, .. }));
+}
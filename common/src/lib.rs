@@ -0,0 +1,11 @@
+pub mod count;
+pub mod input;
+pub mod parse;
+pub mod rng;
+pub mod solution;
+pub mod trace;
+
+pub use count::{Count, Overflow};
+pub use parse::ParseError;
+pub use rng::{Generate, Rng};
+pub use solution::{Solution, Unsolved};
@@ -0,0 +1,225 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+// where a puzzle input stopped making sense, and what we wanted to see there instead.
+// displays like a compiler error, pointing at the offending text:
+//
+//   error: expected a number, found `x1`
+//    --> line 3, column 4
+//     |
+//   3 | 12,x1 -> 5,6
+//     |    ^^
+#[derive(Error, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    // both counted from 1, and the column in characters rather than bytes
+    pub line: usize,
+    pub column: usize,
+    pub found: String,
+    pub expected: String,
+    source_line: String,
+}
+
+impl ParseError {
+    // `span` has to be a slice of `input` itself (anything from `lines`, `split` and friends
+    // will do), since where it sits in `input` is how we know where the problem is
+    pub fn at(input: &str, span: &str, expected: impl Into<String>) -> Self {
+        let start = (span.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        debug_assert!(start <= input.len(), "span isn't part of the input");
+        let start = start.min(input.len());
+
+        let before = &input[..start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: input[line_start..start].chars().count() + 1,
+            found: span.to_owned(),
+            expected: expected.into(),
+            source_line: input[line_start..line_end].trim_end_matches('\r').to_owned(),
+        }
+    }
+
+    // for when the input runs out before it's finished
+    pub fn at_end(input: &str, expected: impl Into<String>) -> Self {
+        ParseError::at(input, &input[input.len()..], expected)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let found = match self.found.lines().next() {
+            Some(found) if !found.is_empty() => format!("`{}`", found),
+            _ if self.column > self.source_line.chars().count() => "the end of the line".to_owned(),
+            _ => "nothing".to_owned(),
+        };
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        let width = self.found.lines().next().map_or(0, |found| found.chars().count()).max(1);
+        writeln!(f, "error: expected {}, found {}", self.expected, found)?;
+        writeln!(f, "{}--> line {}, column {}", gutter, self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), "^".repeat(width))
+    }
+}
+
+// `main` returning one of these prints it with Debug, so make that readable too
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+// the combinators below all take the whole `input` along with the piece of it they're
+// parsing, so that whatever goes wrong can say where it was. they hand back pieces of that
+// same input, so these nest: `sections` into `lines` into `list` into `number`, say
+
+// `s` without any blank lines before it or whitespace after it, so an input saved with an
+// extra newline (or by an editor that likes `\r\n`) parses the same as one without
+pub fn trim_blank(s: &str) -> &str {
+    let s = s.trim_end();
+    let start = s.find(|c: char| !c.is_whitespace()).unwrap_or(s.len());
+    let line_start = s[..start].rfind('\n').map_or(0, |i| i + 1);
+    &s[line_start..]
+}
+
+// the lines of `s`, ending in `\n` or `\r\n`, skipping the blank ones at either end. any
+// whitespace left on the end of a line is trimmed off too, since nobody can see it
+pub fn lines(s: &str) -> impl Iterator<Item = &str> {
+    trim_blank(s).lines().map(str::trim_end)
+}
+
+// the chunks of `s` with blank lines between them, like the boards of day 4 or the scanners
+// of day 19. a run of blank lines counts as one
+pub fn sections(s: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut current: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for line in s.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some((start, end)) = current.take() {
+                sections.push(&s[start..end]);
+            }
+        } else {
+            let start = current.map_or(offset, |(start, _)| start);
+            current = Some((start, offset + line.trim_end().len()));
+        }
+        offset += line.len();
+    }
+
+    if let Some((start, end)) = current {
+        sections.push(&s[start..end]);
+    }
+
+    sections
+}
+
+// signed or not depending on `T`, and never mind any spaces around it
+pub fn number<T: FromStr>(input: &str, span: &str) -> Result<T, ParseError> {
+    let span = span.trim();
+    span.parse().map_err(|_| ParseError::at(input, span, "a number"))
+}
+
+// `s` split up by `separator`, with `item` parsing each piece (spaces around them trimmed).
+// `item` can say where things went wrong itself, so this doesn't need the input
+pub fn list<'a, T>(s: &'a str, separator: &str, mut item: impl FnMut(&'a str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    s.split(separator).map(|piece| item(piece.trim())).collect()
+}
+
+// like `list`, for things that always have exactly `N` pieces, like the `x,y` of a point
+pub fn array<'a, T, const N: usize>(input: &str, s: &'a str, separator: &str, expected: &str, item: impl FnMut(&'a str) -> Result<T, ParseError>) -> Result<[T; N], ParseError> {
+    if s.split(separator).count() != N {
+        return Err(ParseError::at(input, s, expected));
+    }
+
+    let items = list(s, separator, item)?;
+    Ok(items.try_into().unwrap_or_else(|_| unreachable!()))
+}
+
+// each character of `s`, according to `cell` (with `expected` describing the ones it likes)
+pub fn chars<T>(input: &str, s: &str, expected: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Vec<T>, ParseError> {
+    s.char_indices()
+        .map(|(i, c)| cell(c).ok_or_else(|| ParseError::at(input, &s[i..i + c.len_utf8()], expected)))
+        .collect()
+}
+
+// the label and number of a header like `--- scanner 3 ---`
+pub fn header<'a, T: FromStr>(input: &str, line: &'a str) -> Result<(&'a str, T), ParseError> {
+    let expected = "a header like `--- scanner 0 ---`";
+    let inner = line.trim_end().strip_prefix("--- ")
+        .and_then(|l| l.strip_suffix(" ---"))
+        .ok_or_else(|| ParseError::at(input, line, expected))?;
+    let (label, n) = inner.rsplit_once(' ').ok_or_else(|| ParseError::at(input, inner, expected))?;
+    Ok((label, number(input, n)?))
+}
+
+// like str::split_once, but complaining about `s` as a whole if the delimiter isn't there
+pub fn split_once<'a>(input: &str, s: &'a str, delimiter: &str, expected: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter).ok_or_else(|| ParseError::at(input, s, expected))
+}
+
+// like str::strip_prefix, but complaining about whatever's there instead of the prefix
+pub fn strip_prefix<'a>(input: &str, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or_else(|| {
+        let mismatch = s.char_indices()
+            .zip(prefix.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(s.len().min(prefix.len()), |((i, _), _)| i);
+        ParseError::at(input, &s[mismatch..], format!("`{}`", prefix))
+    })
+}
+
+#[test]
+pub fn test_location() {
+    let input = "1,2 -> 3,4\n12,x1 -> 5,6\n";
+    let line = input.lines().nth(1).unwrap();
+    let error = number::<u32>(input, &line[3..5]).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 4));
+    assert_eq!(error.to_string(), "\
+error: expected a number, found `x1`
+ --> line 2, column 4
+  |
+2 | 12,x1 -> 5,6
+  |    ^^");
+
+    let error = split_once(input, line, " => ", "`a => b`").unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 1, line));
+
+    let error = strip_prefix(input, line, "12,y").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 4));
+
+    let error = ParseError::at_end(input, "another line");
+    assert_eq!((error.line, error.column), (3, 1));
+    assert!(error.to_string().starts_with("error: expected another line, found the end of the line"));
+}
+
+#[test]
+pub fn test_combinators() {
+    let input = "\r\n\r\n7,4, 9\r\n\r\n\r\n--- scanner 3 ---\r\n-1,2\r\n\r\n";
+    assert_eq!(trim_blank(input), "7,4, 9\r\n\r\n\r\n--- scanner 3 ---\r\n-1,2");
+    assert_eq!(lines(input).collect::<Vec<_>>(), ["7,4, 9", "", "", "--- scanner 3 ---", "-1,2"]);
+    assert_eq!(lines("  12 \t\n 3\n").collect::<Vec<_>>(), ["  12", " 3"]);
+
+    let sections = sections(input);
+    assert_eq!(sections, ["7,4, 9", "--- scanner 3 ---\r\n-1,2"]);
+    assert_eq!(list(sections[0], ",", |n| number::<u8>(input, n)), Ok(vec![7, 4, 9]));
+
+    let mut scanner = sections[1].lines();
+    assert_eq!(header::<u8>(input, scanner.next().unwrap()), Ok(("scanner", 3)));
+    let beacon = scanner.next().unwrap();
+    assert_eq!(array::<i32, 2>(input, beacon, ",", "x,y", |n| number(input, n)), Ok([-1, 2]));
+
+    // and the errors still point at the right place, line 7 being the beacon
+    let error = array::<i32, 3>(input, beacon, ",", "x,y,z", |n| number(input, n)).unwrap_err();
+    assert_eq!((error.line, error.column, error.expected.as_str()), (7, 1, "x,y,z"));
+    let headers = "--- scanner three ---\n--- 3 ---\n-- scanner 3 --";
+    let mut headers = headers.lines().map(|line| header::<u8>(headers, line).unwrap_err());
+    assert_eq!(headers.next().unwrap().found, "three");
+    assert_eq!(headers.next().unwrap().found, "3");
+    assert_eq!(headers.next().unwrap().expected, "a header like `--- scanner 0 ---`");
+    let error = chars(input, beacon, "a digit", |c| c.to_digit(10)).unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (7, 1, "-"));
+}
@@ -0,0 +1,72 @@
+use std::ops::Range;
+
+// a small splitmix64 generator. nothing clever, but it's the same on every machine and
+// for every version of everything, so a seed always means the same generated input
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // uniform in 0..bound; the modulo bias is irrelevant at the sizes we use
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+        self.next_u64() % bound
+    }
+
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "empty range");
+        range.start + self.below((range.end - range.start) as u64) as i64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+// synthetic puzzle inputs for scale testing. `size` is roughly the number of lines (or the
+// side of a grid) - each day says what it means for it
+pub trait Generate {
+    fn generate(size: usize, seed: u64) -> String;
+}
+
+#[test]
+pub fn test_rng() {
+    let mut first = Rng::new(42);
+    let mut second = Rng::new(42);
+    let first = (0..100).map(|_| first.range(-5..5)).collect::<Vec<_>>();
+    let second = (0..100).map(|_| second.range(-5..5)).collect::<Vec<_>>();
+    assert_eq!(first, second);
+    assert!(first.iter().all(|n| (-5..5).contains(n)));
+    assert!(first.contains(&-5) && first.contains(&4));
+
+    let mut shuffled = (0..10).collect::<Vec<_>>();
+    Rng::new(7).shuffle(&mut shuffled);
+    shuffled.sort_unstable();
+    assert_eq!(shuffled, (0..10).collect::<Vec<_>>());
+}
@@ -0,0 +1,23 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use thiserror::Error;
+
+// the shape every day shares, so the runner (and anything else) can treat them all the same
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+    type Error: Error + Send + Sync + 'static;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error>;
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error>;
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error>;
+}
+
+// for the parts we haven't got round to yet
+#[derive(Error, Debug, Copy, Clone, PartialEq, Eq)]
+#[error("not solved yet")]
+pub struct Unsolved;
@@ -0,0 +1,78 @@
+use std::cell::RefCell;
+use std::fmt;
+
+// something that wants to see each step of a simulation as it happens, e.g. every flash of
+// the day 11 octopuses or every fold of the day 13 paper
+pub trait Observer {
+    fn observe(&mut self, day: u8, step: fmt::Arguments, state: &dyn fmt::Display);
+}
+
+impl<F: FnMut(u8, fmt::Arguments, &dyn fmt::Display)> Observer for F {
+    fn observe(&mut self, day: u8, step: fmt::Arguments, state: &dyn fmt::Display) {
+        self(day, step, state)
+    }
+}
+
+thread_local! {
+    static OBSERVER: RefCell<Option<Box<dyn Observer>>> = RefCell::new(None);
+}
+
+// runs `f` with `observer` watching any snapshots taken on this thread (and only this thread,
+// so the inner loops of the `parallel` feature go unwatched)
+pub fn observe<T>(observer: impl Observer + 'static, f: impl FnOnce() -> T) -> T {
+    let previous = OBSERVER.with(|o| o.replace(Some(Box::new(observer))));
+    let result = f();
+    OBSERVER.with(|o| *o.borrow_mut() = previous);
+    result
+}
+
+// called by the simulations after each step. `step` and `state` only get formatted if
+// somebody's watching, so this is nearly free the rest of the time
+pub fn snapshot(day: u8, step: fmt::Arguments, state: &dyn fmt::Display) {
+    OBSERVER.with(|o| {
+        // an observer taking snapshots of its own would be a bit much, so ignore those
+        if let Ok(mut o) = o.try_borrow_mut() {
+            if let Some(observer) = o.as_mut() {
+                observer.observe(day, step, state);
+            }
+        }
+    })
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Step {
+    pub day: u8,
+    pub step: String,
+    pub state: String,
+}
+
+// runs `f`, and hands back every snapshot it took along with its result
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Vec<Step>) {
+    let steps = std::rc::Rc::new(RefCell::new(Vec::new()));
+    let recorder = steps.clone();
+    let result = observe(move |day, step: fmt::Arguments, state: &dyn fmt::Display| {
+        recorder.borrow_mut().push(Step { day, step: step.to_string(), state: state.to_string() });
+    }, f);
+
+    let steps = steps.take();
+    (result, steps)
+}
+
+#[test]
+pub fn test_record() {
+    let (answer, steps) = record(|| {
+        for i in 1..=3 {
+            snapshot(25, format_args!("step {}", i), &(i * i));
+        }
+        42
+    });
+
+    assert_eq!(answer, 42);
+    assert_eq!(steps.len(), 3);
+    assert_eq!(steps[2], Step { day: 25, step: "step 3".to_owned(), state: "9".to_owned() });
+
+    // and nobody's watching any more
+    let (_, steps) = record(|| ());
+    assert!(steps.is_empty());
+    snapshot(25, format_args!("unseen"), &0);
+}
@@ -0,0 +1,18 @@
+use common::{Generate, Rng};
+
+use crate::Day01;
+
+impl Generate for Day01 {
+    // `size` depth measurements that mostly, but not always, get deeper
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut depth = 100 + rng.range(0..100);
+        let mut measurements = Vec::with_capacity(size);
+        for _ in 0..size {
+            measurements.push(depth.to_string());
+            depth = (depth + rng.range(-10..25)).max(0);
+        }
+
+        measurements.join("\n")
+    }
+}
@@ -0,0 +1,416 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::ops::Range;
+
+use common::{parse, ParseError, Solution};
+use thiserror::Error;
+
+pub mod generate;
+
+// a depth in millimetres. readings come in whole metres or whole feet, and a foot is
+// 304.8mm, so feet are rounded to the nearest millimetre: never far enough to make two
+// different readings the same, or put one the wrong side of another
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Measurement(i32);
+
+impl Measurement {
+    pub fn millimetres(&self) -> i32 {
+        self.0
+    }
+
+    pub fn metres(&self) -> f64 {
+        self.0 as f64 / 1000.0
+    }
+}
+
+#[derive(Error)]
+pub enum ReadError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error("failed to read the measurements: {0}")]
+    Io(#[from] io::Error),
+}
+
+// `main` returning one of these prints it with Debug, so make that readable too
+impl fmt::Debug for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+// None is a reading that's missing. windows don't go across one, since there's no knowing
+// what it would have added up to
+pub type Reading = Option<Measurement>;
+
+const MILLIMETRES_PER_METRE: i64 = 1000;
+// a foot is 304.8mm, which is a whole number of these
+const TENTHS_OF_A_MILLIMETRE_PER_FOOT: i64 = 3048;
+
+// a depth, in metres unless it says it's in feet (`150`, `150m`, `492ft`), or `-` (or a blank
+// line) for a reading that's missing
+pub fn parse_reading(input: &str, line: &str) -> Result<Reading, ParseError> {
+    let reading = line.trim();
+    if reading.is_empty() || reading == "-" {
+        return Ok(None);
+    }
+
+    let (depth, feet) = match reading.strip_suffix("ft") {
+        Some(depth) => (depth, true),
+        None => (reading.strip_suffix('m').unwrap_or(reading), false),
+    };
+    let expected = "a depth like `150`, `150m` or `492ft`, or `-` if it's missing";
+    let depth: i64 = parse::number(input, depth).map_err(|_| ParseError::at(input, reading, expected))?;
+    let millimetres = if feet {
+        // rounded half away from zero, the same either side of the surface
+        depth
+            .checked_mul(TENTHS_OF_A_MILLIMETRE_PER_FOOT)
+            .and_then(|tenths| tenths.checked_add(5 * tenths.signum()))
+            .map(|tenths| tenths / 10)
+    } else {
+        depth.checked_mul(MILLIMETRES_PER_METRE)
+    };
+    match millimetres.and_then(|millimetres| i32::try_from(millimetres).ok()) {
+        Some(millimetres) => Ok(Some(Measurement(millimetres))),
+        None => Err(ParseError::at(input, reading, "a depth within 2000km of the surface")),
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Reading>, ParseError> {
+    parse::lines(input).map(|l| parse_reading(input, l)).collect()
+}
+
+// for logs where some of the lines are garbled: those are gaps, like missing readings, and
+// what was wrong with each comes back alongside everything that could be read
+pub fn parse_lenient(input: &str) -> (Vec<Reading>, Vec<ParseError>) {
+    let mut errors = Vec::new();
+    let readings = parse::lines(input)
+        .map(|l| parse_reading(input, l).unwrap_or_else(|e| {
+            errors.push(e);
+            None
+        }))
+        .collect();
+
+    (readings, errors)
+}
+
+// the readings a line at a time, for logs too big to read in all at once
+pub fn read_measurements(reader: impl BufRead) -> impl Iterator<Item = Result<Reading, ReadError>> {
+    reader.lines().enumerate().map(|(i, line)| {
+        let line = line?;
+        // the error can only see this one line, so it needs telling which line it was
+        parse_reading(&line, &line).map_err(|mut e| {
+            e.line = i + 1;
+            e.into()
+        })
+    })
+}
+
+// counts how often the sum of `window` measurements in a row is more than the sum of the
+// ones starting just before it, fed a measurement at a time. next to each other, the two
+// windows share everything but the measurement going out of the first and the one coming
+// into the second, so it's more exactly when the one coming in is deeper. that means only
+// the last `window` measurements need keeping, however long the log
+pub struct WindowIncreases {
+    window: usize,
+    recent: VecDeque<Measurement>,
+    increases: usize,
+}
+
+impl WindowIncreases {
+    pub fn new(window: usize) -> Self {
+        WindowIncreases { window, recent: VecDeque::with_capacity(window + 1), increases: 0 }
+    }
+
+    pub fn push(&mut self, measurement: Measurement) {
+        if self.recent.len() == self.window {
+            match self.recent.pop_front() {
+                Some(going_out) if going_out < measurement => self.increases += 1,
+                Some(_) => (),
+                // windows of nothing all add up to 0
+                None => return,
+            }
+        }
+        self.recent.push_back(measurement);
+    }
+
+    // the next measurement starts afresh, without any windows from before
+    pub fn gap(&mut self) {
+        self.recent.clear();
+    }
+
+    pub fn push_reading(&mut self, reading: Reading) {
+        match reading {
+            Some(measurement) => self.push(measurement),
+            None => self.gap(),
+        }
+    }
+
+    pub fn increases(&self) -> usize {
+        self.increases
+    }
+}
+
+pub fn count_increases(measurements: impl IntoIterator<Item = Measurement>, window: usize) -> usize {
+    let mut analyser = WindowIncreases::new(window);
+    measurements.into_iter().for_each(|m| analyser.push(m));
+    analyser.increases()
+}
+
+// the same, but for readings with gaps in, which start the windows again
+pub fn count_increases_with_gaps(readings: impl IntoIterator<Item = Reading>, window: usize) -> usize {
+    let mut analyser = WindowIncreases::new(window);
+    readings.into_iter().for_each(|r| analyser.push_reading(r));
+    analyser.increases()
+}
+
+pub fn part1(readings: &[Reading]) -> usize {
+    count_increases_with_gaps(readings.iter().copied(), 1)
+}
+
+pub fn part2(readings: &[Reading]) -> usize {
+    count_increases_with_gaps(readings.iter().copied(), 3)
+}
+
+// the biggest change in depth from one measurement to the next, either way
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Jump {
+    // the two measurements either side of it
+    pub range: Range<usize>,
+    // wider than a depth, since it can go from one end of them to the other
+    pub change: i64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Outlier {
+    pub index: usize,
+    pub measurement: Measurement,
+    // how many standard deviations it is from the mean, negative if it's shallower
+    pub deviations: f64,
+}
+
+// what a depth log looks like, beyond how often it goes down. every range is of indices
+// into the readings it was made from, and none of them go across a gap: like the windows
+// of `WindowIncreases`, runs, jumps and averages all start again after one. depths (so the
+// jumps, averages and the like too) are in millimetres, like the measurements
+#[derive(Clone, PartialEq, Debug)]
+pub struct SweepReport {
+    // the first of the longest, if there's a tie. a single measurement counts
+    pub longest_increase: Option<Range<usize>>,
+    // every run of at least two measurements the same
+    pub plateaus: Vec<Range<usize>>,
+    pub largest_jump: Option<Jump>,
+    // the average over every `window` measurements in a row, with the ones it's over
+    pub moving_averages: Vec<(Range<usize>, f64)>,
+    // these are over all the measurements, whatever gaps there are between them
+    pub mean: Option<f64>,
+    pub standard_deviation: Option<f64>,
+    // everything more than `k` standard deviations from the mean
+    pub outliers: Vec<Outlier>,
+}
+
+impl SweepReport {
+    pub fn new(readings: &[Reading], window: usize, k: f64) -> Self {
+        let longest_increase = runs(readings, |a, b| a < b)
+            .into_iter()
+            .reduce(|longest, run| if run.len() > longest.len() { run } else { longest });
+        let plateaus = runs(readings, |a, b| a == b).into_iter().filter(|run| run.len() > 1).collect();
+
+        let largest_jump = readings
+            .windows(2)
+            .enumerate()
+            .filter_map(|(i, w)| match w {
+                [Some(a), Some(b)] => Some(Jump { range: i..i + 2, change: i64::from(b.0) - i64::from(a.0) }),
+                _ => None,
+            })
+            .reduce(|largest, jump| if jump.change.abs() > largest.change.abs() { jump } else { largest });
+
+        let moving_averages = if window == 0 { Vec::new() } else {
+            readings
+                .windows(window)
+                .enumerate()
+                .filter_map(|(i, w)| {
+                    let sum = w.iter().map(|r| r.map(|m| m.0 as f64)).sum::<Option<f64>>()?;
+                    Some((i..i + window, sum / window as f64))
+                })
+                .collect()
+        };
+
+        let measurements = readings.iter().enumerate().filter_map(|(i, r)| r.map(|m| (i, m))).collect::<Vec<_>>();
+        let count = measurements.len() as f64;
+        let mean = Some(measurements.iter().map(|(_, m)| m.0 as f64).sum::<f64>() / count).filter(|_| count > 0.0);
+        let standard_deviation = mean.map(|mean| {
+            (measurements.iter().map(|(_, m)| (m.0 as f64 - mean).powi(2)).sum::<f64>() / count).sqrt()
+        });
+
+        let outliers = match (mean, standard_deviation) {
+            // all the same, so nothing stands out
+            (Some(mean), Some(sd)) if sd > 0.0 => measurements
+                .into_iter()
+                .map(|(index, measurement)| Outlier { index, measurement, deviations: (measurement.0 as f64 - mean) / sd })
+                .filter(|outlier| outlier.deviations.abs() > k)
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        SweepReport { longest_increase, plateaus, largest_jump, moving_averages, mean, standard_deviation, outliers }
+    }
+}
+
+// splits the readings up at every gap, and wherever `joined` says two measurements next to
+// each other don't belong in the same run
+fn runs(readings: &[Reading], joined: impl Fn(&Measurement, &Measurement) -> bool) -> Vec<Range<usize>> {
+    let mut runs = Vec::new();
+    let mut start = None;
+    for (i, w) in readings.windows(2).enumerate() {
+        let together = match w {
+            [Some(a), Some(b)] => joined(a, b),
+            _ => false,
+        };
+        if w[0].is_some() {
+            start.get_or_insert(i);
+        }
+        if !together {
+            if let Some(start) = start.take() {
+                runs.push(start..i + 1);
+            }
+        }
+    }
+    // the last one hasn't had anything after it to end its run
+    if let Some(Some(_)) = readings.last() {
+        runs.push(start.unwrap_or(readings.len() - 1)..readings.len());
+    }
+
+    runs
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Reading>;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = ParseError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(part2(input))
+    }
+}
+
+#[test]
+pub fn test_windows() {
+    let example = include_str!("../fixtures/example.txt");
+    let readings = parse_input(example).unwrap();
+    let measurements = readings.iter().flatten().copied().collect::<Vec<_>>();
+    // the same as summing up every window and comparing those
+    for window in 1..=5 {
+        let sums = measurements.windows(window).map(|w| w.iter().map(|m| m.0).sum()).collect::<Vec<i32>>();
+        let expected = sums.windows(2).filter(|w| w[0] < w[1]).count();
+        assert_eq!(count_increases(measurements.iter().copied(), window), expected, "window of {}", window);
+        assert_eq!(count_increases_with_gaps(readings.iter().copied(), window), expected, "window of {}", window);
+    }
+    assert_eq!(count_increases(measurements.iter().copied(), 0), 0);
+    assert_eq!(count_increases(measurements.iter().copied(), 20), 0);
+
+    let streamed = read_measurements(example.replace('\n', "\r\n").as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(streamed, readings);
+    let error = read_measurements("199\n\n200\n2x0\n".as_bytes()).find_map(Result::err).unwrap();
+    match error {
+        ReadError::Parse(e) => assert_eq!((e.line, e.found.as_str()), (4, "2x0")),
+        e => panic!("expected a parse error, got {}", e),
+    }
+}
+
+#[test]
+pub fn test_report() {
+    let measurements = parse_input(include_str!("../fixtures/example.txt")).unwrap();
+    // 199 200 208 210 200 207 240 269 260 263
+    let report = SweepReport::new(&measurements, 3, 1.5);
+    // 200 207 240 269 is as long, but comes later
+    assert_eq!(report.longest_increase, Some(0..4));
+    assert!(report.plateaus.is_empty());
+    assert_eq!(report.largest_jump, Some(Jump { range: 5..7, change: 33_000 }));
+    assert_eq!(report.moving_averages.len(), 8);
+    assert_eq!(report.moving_averages[1], (1..4, 206_000.0));
+    assert_eq!(report.mean, Some(225_600.0));
+    // the standard deviation's about 27.6, so only 269 is more than 41 away from the mean
+    assert_eq!(report.outliers.iter().map(|o| o.index).collect::<Vec<_>>(), [7]);
+    let outliers = SweepReport::new(&measurements, 3, 1.0).outliers;
+    assert_eq!(outliers.iter().map(|o| o.index).collect::<Vec<_>>(), [7, 8, 9]);
+    assert_eq!(outliers[0].measurement.metres(), 269.0);
+    assert!(outliers[0].deviations > 1.5);
+
+    let flat = parse_input("5\n5\n5\n3\n3\n4\n").unwrap();
+    let report = SweepReport::new(&flat, 10, 1.0);
+    assert_eq!(report.plateaus, [0..3, 3..5]);
+    assert_eq!(report.longest_increase, Some(4..6));
+    assert_eq!(report.largest_jump.map(|jump| jump.change), Some(-2000));
+    assert!(report.moving_averages.is_empty());
+
+    // right down to the bottom of what fits and back up again
+    let readings = parse_input("2000000m\n-2000000m\n2000000m\n").unwrap();
+    let report = SweepReport::new(&readings, 2, 1.0);
+    assert_eq!(report.largest_jump, Some(Jump { range: 0..2, change: -4_000_000_000 }));
+
+    let empty = SweepReport::new(&[], 3, 1.0);
+    assert_eq!((empty.longest_increase, empty.largest_jump, empty.mean), (None, None, None));
+}
+
+#[test]
+pub fn test_readings() {
+    let readings = parse_input("150\n150m\n 492ft \n-\n\n3 m\n").unwrap();
+    assert_eq!(readings, [Some(Measurement(150_000)), Some(Measurement(150_000)), Some(Measurement(149_962)), None, None, Some(Measurement(3000))]);
+
+    // a foot apart is still a foot apart, and not a plateau
+    let readings = parse_input("1ft\n2ft\n3ft\n1m\n").unwrap();
+    assert_eq!(readings.iter().flatten().map(Measurement::millimetres).collect::<Vec<_>>(), [305, 610, 914, 1000]);
+    let report = SweepReport::new(&readings, 2, 1.0);
+    assert!(report.plateaus.is_empty());
+    assert_eq!(report.longest_increase, Some(0..4));
+    assert_eq!(parse_input("-3000ft\n").unwrap()[0].map(|m| m.metres()), Some(-914.4));
+    for (input, line) in [("1\n3000000m\n", 2), ("9300000000000000\n", 1), ("-9300000000000000m\n", 1),
+        ("9300000000000000ft\n", 1), ("1\n-9300000000000000ft\n", 2), ("7050000ft\n", 1)]
+    {
+        let error = parse_input(input).unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (line, "a depth within 2000km of the surface"), "{}", input);
+    }
+
+    let error = parse_input("150\n15o\n").unwrap_err();
+    assert_eq!((error.line, error.found.as_str()), (2, "15o"));
+    assert!(parse_input("12fathoms\n").is_err());
+
+    // a gap starts the windows again: 1 2 | 3 4 5 only goes up three times
+    let readings = parse_input("1\n2\n-\n3\n4\n5\n").unwrap();
+    assert_eq!(part1(&readings), 3);
+    assert_eq!(count_increases_with_gaps(readings.iter().copied(), 2), 1);
+    assert_eq!(count_increases(readings.iter().flatten().copied(), 2), 3);
+
+    let (readings, errors) = parse_lenient("1\n2\n?\n3\n4\nfour\n5\n");
+    assert_eq!(readings.len(), 7);
+    assert_eq!(errors.iter().map(|e| e.line).collect::<Vec<_>>(), [3, 6]);
+    assert_eq!(part1(&readings), 2);
+}
+
+#[test]
+pub fn test_report_gaps() {
+    // it'd all be one run going up without the gaps
+    let readings = parse_input("1\n2\n3\n-\n4\n5\n\n\n6\n7\n8\n9\n-\n").unwrap();
+    let report = SweepReport::new(&readings, 2, 1.0);
+    assert_eq!(report.longest_increase, Some(8..12));
+    assert_eq!(report.largest_jump.map(|jump| jump.range), Some(0..2));
+    assert_eq!(report.moving_averages.iter().map(|(range, _)| range.clone()).collect::<Vec<_>>(), [0..2, 1..3, 4..6, 8..10, 9..11, 10..12]);
+    assert_eq!(report.mean, Some(5000.0));
+
+    let readings = parse_input("5\n5\n-\n5\n5\n5\n").unwrap();
+    assert_eq!(SweepReport::new(&readings, 2, 1.0).plateaus, [0..2, 3..6]);
+}
@@ -1,44 +1,40 @@
-use std::num::ParseIntError;
-
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
-pub struct Measurement(i32);
-
-impl TryFrom<&str> for Measurement {
-    type Error = ParseIntError;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Ok(Measurement(value.parse()?))
+use std::env;
+
+use common::input;
+use day01::{read_measurements, ReadError, WindowIncreases};
+
+// reads the measurements as they come rather than all at once, so the log can be as long as
+// it likes (`day01 - < sonar.log`). with `--lenient`, lines that don't make sense are gaps,
+// reported on stderr, instead of stopping everything
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let (flags, args): (Vec<String>, Vec<String>) = env::args().skip(1).partition(|arg| arg.starts_with("--"));
+    let lenient = flags.iter().any(|flag| flag == "--lenient");
+    if let Some(flag) = flags.iter().find(|flag| *flag != "--lenient") {
+        eprintln!("unknown flag {} (the only one is --lenient)", flag);
+        std::process::exit(2);
     }
-}
-
-pub fn parse_input(input: &str) -> Vec<Measurement> {
-    input.lines().map(|l| l.try_into().unwrap()).collect()
-}
-
-pub fn part1(measurements: &[Measurement]) -> usize {
-    measurements
-        .windows(2)
-        .filter(|w| w[0] < w[1])
-        .count()
-}
-
-pub fn part2(measurements: &[Measurement]) -> usize {
-    let summed_windows = measurements
-        .windows(3)
-        .map(|w| w.iter().map(|m| m.0).sum())
-        .collect::<Vec<i32>>();
-
-    summed_windows
-        .windows(2)
-        .filter(|w| w[0] < w[1])
-        .count()
-}
 
+    let source = input::resolve(1, args.first().map(String::as_str));
+    let (mut part1, mut part2) = (WindowIncreases::new(1), WindowIncreases::new(3));
+    let mut skipped = 0;
+    for reading in read_measurements(input::open(1, &source)?) {
+        let reading = match reading {
+            Err(ReadError::Parse(e)) if lenient => {
+                eprintln!("{}\n", e);
+                skipped += 1;
+                None
+            }
+            reading => reading?,
+        };
+        part1.push_reading(reading);
+        part2.push_reading(reading);
+    }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let measurements = parse_input(input);
+    if skipped > 0 {
+        eprintln!("skipped {} line{} that didn't make sense", skipped, if skipped == 1 { "" } else { "s" });
+    }
+    println!("Part 1: {}", part1.increases());
+    println!("Part 2: {}", part2.increases());
 
-    println!("Part 1: {}", part1(&measurements));
-    println!("Part 2: {}", part2(&measurements));
+    Ok(())
 }
@@ -0,0 +1,42 @@
+use common::{Generate, Rng};
+
+use crate::Day02;
+
+impl Generate for Day02 {
+    // `size` commands. down and up move part 1's depth and part 2's aim by the same amount,
+    // so keeping that between 0 and 20 means the submarine never leaves the water
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut aim = 0;
+        let mut commands = Vec::with_capacity(size);
+        for _ in 0..size {
+            let v = rng.range(1..10);
+            match rng.index(3) {
+                1 if aim >= v => {
+                    aim -= v;
+                    commands.push(format!("up {}", v));
+                }
+                2 if aim + v <= 20 => {
+                    aim += v;
+                    commands.push(format!("down {}", v));
+                }
+                _ => commands.push(format!("forward {}", v)),
+            }
+        }
+
+        commands.join("\n")
+    }
+}
+
+#[test]
+pub fn test_generate() {
+    use crate::{follow_commands, parse_input, Position, SurfacePolicy};
+
+    for seed in 0..20 {
+        let commands = parse_input(&Day02::generate(1000, seed)).unwrap();
+        assert_eq!(commands.len(), 1000);
+        // these would be breaches if the generator got it wrong
+        follow_commands(&commands, Position::follow_part1, SurfacePolicy::Forbid).unwrap();
+        follow_commands(&commands, Position::follow_part2, SurfacePolicy::Forbid).unwrap();
+    }
+}
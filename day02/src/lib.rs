@@ -0,0 +1,196 @@
+use std::fmt;
+
+use common::{parse, ParseError, Solution};
+use thiserror::Error;
+
+pub mod generate;
+pub mod trajectory;
+
+pub use trajectory::Trajectory;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Command {
+    Forward(u32),
+    Up(u32),
+    Down(u32),
+}
+
+impl Command {
+    // `line` is one line of `input`, which is just there so errors can say where they are
+    pub fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (direction, v) = parse::split_once(input, line, " ", "a command like `forward 5`")?;
+        match direction {
+            "forward" => Ok(Self::Forward(parse::number(input, v)?)),
+            "up" => Ok(Self::Up(parse::number(input, v)?)),
+            "down" => Ok(Self::Down(parse::number(input, v)?)),
+            _ => Err(ParseError::at(input, direction, "`forward`, `up` or `down`")),
+        }
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Forward(v) => write!(f, "forward {}", v),
+            Command::Up(v) => write!(f, "up {}", v),
+            Command::Down(v) => write!(f, "down {}", v),
+        }
+    }
+}
+
+// a negative depth is above the surface, and a negative aim is pointing up
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct Position {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl Position {
+    // these just say where a command goes, even if that's out of the water: it's up to
+    // `follow_commands` what to do about that. none if it's further than an i64 goes
+    pub fn follow_part1(&self, command: &Command) -> Option<Position> {
+        let mut next = *self;
+        match *command {
+            Command::Forward(v) => next.horizontal = self.horizontal.checked_add(v.into())?,
+            Command::Up(v) => next.depth = self.depth.checked_sub(v.into())?,
+            Command::Down(v) => next.depth = self.depth.checked_add(v.into())?,
+        }
+        Some(next)
+    }
+
+    pub fn follow_part2(&self, command: &Command) -> Option<Position> {
+        let mut next = *self;
+        match *command {
+            Command::Forward(v) => {
+                next.horizontal = self.horizontal.checked_add(v.into())?;
+                next.depth = self.aim.checked_mul(v.into()).and_then(|dive| self.depth.checked_add(dive))?;
+            }
+            Command::Up(v) => next.aim = self.aim.checked_sub(v.into())?,
+            Command::Down(v) => next.aim = self.aim.checked_add(v.into())?,
+        }
+        Some(next)
+    }
+}
+
+// what to do when a command would take the submarine above the surface
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SurfacePolicy {
+    // it stops at the surface, and carries on from there
+    Clamp,
+    // the course is wrong, and following it stops there
+    Forbid,
+    // it can fly, so the depth just goes negative
+    Allow,
+}
+
+#[derive(Error, Clone, PartialEq, Eq, Debug)]
+pub enum CourseError {
+    // `index` counts from 0, like the commands it's into
+    #[error("command {} (`{command}`) would take the submarine {} above the surface", .index + 1, -.depth)]
+    Breach { index: usize, command: Command, depth: i64 },
+    #[error("command {} (`{command}`) takes the submarine further than an i64 can count", .index + 1)]
+    Overflow { index: usize, command: Command },
+    #[error("the answer doesn't fit in an i64")]
+    Answer,
+}
+
+#[derive(Error, Debug)]
+pub enum SolutionError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Course(#[from] CourseError),
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    parse::lines(input)
+        .map(|line| Command::parse(input, line))
+        .collect()
+}
+
+// everywhere the submarine goes following `commands`
+pub fn record(commands: &[Command], rule: fn(&Position, &Command) -> Option<Position>,
+    policy: SurfacePolicy
+) -> Result<Trajectory, CourseError> {
+    let mut trajectory = Trajectory::with_capacity(commands.len());
+    let mut position = Position::default();
+    for (index, &command) in commands.iter().enumerate() {
+        position = rule(&position, &command).ok_or(CourseError::Overflow { index, command })?;
+        if position.depth < 0 {
+            match policy {
+                SurfacePolicy::Clamp => position.depth = 0,
+                SurfacePolicy::Forbid => return Err(CourseError::Breach { index, command, depth: position.depth }),
+                SurfacePolicy::Allow => (),
+            }
+        }
+        trajectory.push(command, position);
+    }
+
+    Ok(trajectory)
+}
+
+pub fn follow_commands(commands: &[Command], rule: fn(&Position, &Command) -> Option<Position>,
+    policy: SurfacePolicy
+) -> Result<i64, CourseError> {
+    let end = record(commands, rule, policy)?.end();
+    end.horizontal.checked_mul(end.depth).ok_or(CourseError::Answer)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Command>;
+    type Output1 = i64;
+    type Output2 = i64;
+    type Error = SolutionError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_input(input)?)
+    }
+
+    // a puzzle course never leaves the water, so if this one does it's not one
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(follow_commands(input, Position::follow_part1, SurfacePolicy::Forbid)?)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(follow_commands(input, Position::follow_part2, SurfacePolicy::Forbid)?)
+    }
+}
+
+#[test]
+pub fn test_parse_error() {
+    let error = parse_input("forward 5\nsideways 3").unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 1, "sideways"));
+
+    let error = parse_input("forward 5\ndown x").unwrap_err();
+    assert_eq!((error.line, error.column, error.expected.as_str()), (2, 6, "a number"));
+}
+
+#[test]
+pub fn test_surface() {
+    // part 1 goes 2 above the surface on the third command, then down 5
+    let commands = parse_input("forward 5\ndown 1\nup 3\ndown 5\n").unwrap();
+    let error = follow_commands(&commands, Position::follow_part1, SurfacePolicy::Forbid).unwrap_err();
+    assert_eq!(error, CourseError::Breach { index: 2, command: Command::Up(3), depth: -2 });
+    assert_eq!(error.to_string(), "command 3 (`up 3`) would take the submarine 2 above the surface");
+    assert_eq!(follow_commands(&commands, Position::follow_part1, SurfacePolicy::Clamp), Ok(25));
+    assert_eq!(follow_commands(&commands, Position::follow_part1, SurfacePolicy::Allow), Ok(15));
+
+    // part 2 only ever changes the aim there, which is fine pointing up, until it goes forward
+    assert_eq!(follow_commands(&commands, Position::follow_part2, SurfacePolicy::Forbid), Ok(0));
+    let commands = parse_input("up 2\nforward 3\ndown 4\nforward 1\n").unwrap();
+    let error = follow_commands(&commands, Position::follow_part2, SurfacePolicy::Forbid).unwrap_err();
+    assert!(matches!(error, CourseError::Breach { index: 1, depth: -6, .. }));
+    assert_eq!(follow_commands(&commands, Position::follow_part2, SurfacePolicy::Clamp), Ok(8));
+    assert_eq!(follow_commands(&commands, Position::follow_part2, SurfacePolicy::Allow), Ok(-16));
+
+    // 2^31 down and forward is 2^62 deep, and twice that is just too deep
+    let commands = [Command::Down(1 << 31), Command::Forward(1 << 31), Command::Forward(1 << 31)];
+    let error = follow_commands(&commands, Position::follow_part2, SurfacePolicy::Forbid).unwrap_err();
+    assert_eq!(error, CourseError::Overflow { index: 2, command: Command::Forward(1 << 31) });
+    assert_eq!(follow_commands(&commands[..2], Position::follow_part2, SurfacePolicy::Forbid), Err(CourseError::Answer));
+}
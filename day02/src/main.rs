@@ -1,84 +1,46 @@
-use std::num::ParseIntError;
-use thiserror::Error;
+use std::env;
 
-pub enum Command {
-    Forward(u32),
-    Up(u32),
-    Down(u32),
-}
-
-#[derive(Error, Debug)]
-pub enum CommandParseError {
-    #[error("failed to parse command: {0}")]
-    InvalidCommand(String),
-    #[error("failed to parse number: {0}")]
-    InvalidNumber(#[from] ParseIntError),
-}
+use day02::{follow_commands, parse_input, record, Position, SurfacePolicy};
 
-impl TryFrom<&str> for Command {
-    type Error = CommandParseError;
+const FLAGS: [&str; 4] = ["--csv", "--geojson", "--part1", "--part2"];
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut components = value.split_whitespace();
-        match (components.next(), components.next()) {
-            (Some("forward"), Some(v)) => Ok(Self::Forward(v.parse()?)),
-            (Some("up"), Some(v)) => Ok(Self::Up(v.parse()?)),
-            (Some("down"), Some(v)) => Ok(Self::Down(v.parse()?)),
-            (Some(s), _) => Err(Self::Error::InvalidCommand(s.to_owned())),
-            (_, _) => Err(Self::Error::InvalidCommand(String::new()))
-        }
+// with `--csv` or `--geojson`, prints the course for plotting instead of the answers. it's
+// the way part 2 reads it (with aim) unless `--part1` says to read it the part 1 way
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let (flags, args): (Vec<String>, Vec<String>) = env::args().skip(1).partition(|arg| arg.starts_with("--"));
+    if let Some(flag) = flags.iter().find(|flag| !FLAGS.contains(&flag.as_str())) {
+        eprintln!("unknown flag {} (try --csv or --geojson, and --part1 or --part2)", flag);
+        std::process::exit(2);
     }
-}
-
-#[derive(PartialEq, Eq, Hash, Default, Debug)]
-pub struct Position {
-    horizontal : u32,
-    depth: u32,
-    aim: u32,
-}
-
-impl Position {
-    pub fn follow_part1(&mut self, command: &Command) {
-        match command {
-            Command::Forward(v) => self.horizontal += v,
-            Command::Up(v) => self.depth -= v,
-            Command::Down(v) => self.depth += v
+    let has = |flag: &str| flags.iter().any(|f| f == flag);
+    for (a, b) in [("--csv", "--geojson"), ("--part1", "--part2")] {
+        if has(a) && has(b) {
+            eprintln!("{} and {} can't go together, pick one", a, b);
+            std::process::exit(2);
         }
     }
-
-    pub fn follow_part2(&mut self, command: &Command) {
-        match command {
-            Command::Forward(v) => {
-                self.horizontal += v;
-                self.depth += self.aim * v;
-            },
-            Command::Up(v) => self.aim -= v,
-            Command::Down(v) => self.aim += v,
-        }
+    let format = if has("--csv") { Some("--csv") } else if has("--geojson") { Some("--geojson") } else { None };
+    if format.is_none() && (has("--part1") || has("--part2")) {
+        eprintln!("--part1 and --part2 only choose what --csv or --geojson prints");
+        std::process::exit(2);
     }
-}
-
-pub fn parse_input(input: &str) -> Result<Vec<Command>, CommandParseError> {
-    input
-        .lines()
-        .map(Command::try_from)
-        .collect()
-}
 
-pub fn follow_commands(commands: &[Command], rule: fn(&mut Position, &Command) -> ()) -> u32 {
-    let mut position = Position::default();
-    for c in commands {
-        rule(&mut position, c)
+    let input = common::input::load(2, args.first().map(String::as_str))?;
+    let commands = parse_input(&input)?;
+    if let Some(format) = format {
+        let rule = if has("--part1") { Position::follow_part1 } else { Position::follow_part2 };
+        let trajectory = record(&commands, rule, SurfacePolicy::Forbid)?;
+        let (step, deepest) = trajectory.deepest();
+        eprintln!("deepest: {} at step {}", deepest.depth, step);
+        match format {
+            "--csv" => print!("{}", trajectory.to_csv()),
+            _ => print!("{}", trajectory.to_geojson()),
+        }
+        return Ok(());
     }
 
-    position.horizontal * position.depth
-}
-
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = include_str!("../input.txt");
-    let commands = parse_input(input)?;
-    println!("Part 1: {}", follow_commands(&commands, Position::follow_part1));
-    println!("Part 2: {}", follow_commands(&commands, Position::follow_part2));
+    println!("Part 1: {}", follow_commands(&commands, Position::follow_part1, SurfacePolicy::Forbid)?);
+    println!("Part 2: {}", follow_commands(&commands, Position::follow_part2, SurfacePolicy::Forbid)?);
 
     Ok(())
 }
@@ -0,0 +1,139 @@
+use std::fmt::Write;
+
+use crate::{Command, Position};
+
+// where the submarine was after every command. step n is where it was after the first n
+// commands, so step 0 is the start and there's one more step than there were commands
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Trajectory {
+    commands: Vec<Command>,
+    positions: Vec<Position>,
+}
+
+impl Trajectory {
+    pub fn with_capacity(commands: usize) -> Self {
+        let mut positions = Vec::with_capacity(commands + 1);
+        positions.push(Position::default());
+        Trajectory { commands: Vec::with_capacity(commands), positions }
+    }
+
+    // the next step: `command` took the submarine to `position`
+    pub fn push(&mut self, command: Command, position: Position) {
+        self.commands.push(command);
+        self.positions.push(position);
+    }
+
+    pub fn positions(&self) -> &[Position] {
+        &self.positions
+    }
+
+    // the command that took it to step `step`, which is none for the start
+    pub fn command(&self, step: usize) -> Option<Command> {
+        step.checked_sub(1).and_then(|i| self.commands.get(i)).copied()
+    }
+
+    pub fn end(&self) -> Position {
+        self.positions[self.positions.len() - 1]
+    }
+
+    // the first step at the deepest it got
+    pub fn deepest(&self) -> (usize, Position) {
+        self.extreme(|position| position.depth)
+    }
+
+    // the first step at the shallowest it got, which is above the surface if it flew
+    pub fn shallowest(&self) -> (usize, Position) {
+        self.extreme(|position| -position.depth)
+    }
+
+    fn extreme(&self, key: impl Fn(&Position) -> i64) -> (usize, Position) {
+        let mut best = (0, self.positions[0]);
+        for (step, position) in self.positions.iter().enumerate() {
+            if key(position) > key(&best.1) {
+                best = (step, *position);
+            }
+        }
+        best
+    }
+
+    // a row for each step, with the command that got it there (nothing for the start)
+    pub fn to_csv(&self) -> String {
+        let mut csv = "step,command,horizontal,depth,aim\n".to_owned();
+        for (step, position) in self.positions.iter().enumerate() {
+            let command = self.command(step).map(|c| c.to_string()).unwrap_or_default();
+            writeln!(csv, "{},{},{},{},{}", step, command, position.horizontal, position.depth, position.aim).unwrap();
+        }
+        csv
+    }
+
+    // the dive profile as a GeoJSON Feature with a LineString of every step. x is how far
+    // along it is and y how high (so minus the depth), which is the right way up for a
+    // profile, if not a real place on the globe. a LineString needs two positions at least,
+    // so a course with no commands (and only the start) is a Point instead
+    pub fn to_geojson(&self) -> String {
+        let coordinates = self.positions
+            .iter()
+            .map(|position| format!("[{},{}]", position.horizontal, -position.depth))
+            .collect::<Vec<_>>();
+        let geometry = match &coordinates[..] {
+            [start] => format!(r#"{{"type":"Point","coordinates":{}}}"#, start),
+            _ => format!(r#"{{"type":"LineString","coordinates":[{}]}}"#, coordinates.join(",")),
+        };
+        let deepest = self.deepest();
+        format!(
+            concat!(
+                r#"{{"type":"Feature","#,
+                r#""properties":{{"steps":{},"deepest_step":{},"max_depth":{}}},"#,
+                r#""geometry":{}}}"#,
+                "\n"
+            ),
+            self.positions.len() - 1, deepest.0, deepest.1.depth, geometry
+        )
+    }
+}
+
+#[test]
+pub fn test_trajectory() {
+    use crate::{parse_input, record, SurfacePolicy};
+
+    let commands = parse_input(include_str!("../fixtures/example.txt")).unwrap();
+    let trajectory = record(&commands, Position::follow_part2, SurfacePolicy::Forbid).unwrap();
+    assert_eq!(trajectory.positions().len(), commands.len() + 1);
+    assert_eq!(trajectory.end(), Position { horizontal: 15, depth: 60, aim: 10 });
+    assert_eq!(trajectory.deepest(), (6, Position { horizontal: 15, depth: 60, aim: 10 }));
+    assert_eq!(trajectory.shallowest().0, 0);
+    assert_eq!((trajectory.command(0), trajectory.command(1)), (None, Some(Command::Forward(5))));
+
+    let csv = trajectory.to_csv();
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), commands.len() + 2);
+    assert_eq!(lines[..3], ["step,command,horizontal,depth,aim", "0,,0,0,0", "1,forward 5,5,0,0"]);
+    assert_eq!(lines[4], "3,forward 8,13,40,5");
+
+    let geojson = trajectory.to_geojson();
+    assert!(geojson.starts_with(r#"{"type":"Feature","properties":{"steps":6,"deepest_step":6,"max_depth":60},"#));
+    assert!(geojson.trim_end().ends_with(r#""coordinates":[[0,0],[5,0],[5,0],[13,-40],[13,-40],[13,-40],[15,-60]]}}"#));
+
+    // the part 1 way, down goes straight down and there's no aim
+    let trajectory = record(&commands, Position::follow_part1, SurfacePolicy::Forbid).unwrap();
+    assert_eq!(trajectory.end(), Position { horizontal: 15, depth: 10, aim: 0 });
+    assert_eq!(trajectory.to_csv().lines().nth(4), Some("3,forward 8,13,5,0"));
+
+    // flying goes above the surface, and the first deepest step is the one that counts
+    let commands = parse_input("down 2\nup 5\ndown 3\nforward 1\n").unwrap();
+    let trajectory = record(&commands, Position::follow_part1, SurfacePolicy::Allow).unwrap();
+    assert_eq!(trajectory.deepest().0, 1);
+    assert_eq!(trajectory.shallowest(), (2, Position { horizontal: 0, depth: -3, aim: 0 }));
+
+    // nowhere to go, so it's just the start
+    let trajectory = record(&parse_input("").unwrap(), Position::follow_part2, SurfacePolicy::Forbid).unwrap();
+    assert_eq!(trajectory.to_csv(), "step,command,horizontal,depth,aim\n0,,0,0,0\n");
+    assert_eq!(
+        trajectory.to_geojson(),
+        concat!(
+            r#"{"type":"Feature","properties":{"steps":0,"deepest_step":0,"max_depth":0},"#,
+            r#""geometry":{"type":"Point","coordinates":[0,0]}}"#,
+            "\n"
+        )
+    );
+}
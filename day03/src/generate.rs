@@ -0,0 +1,45 @@
+use std::collections::HashSet;
+
+use common::{Generate, Rng};
+
+use crate::{parse_input, part2, Day03};
+
+impl Generate for Day03 {
+    // `size` distinct entries, rounded up to an odd number so no bit position is ever a tie
+    // for part 1, and wide enough that there's plenty of room for them all
+    fn generate(size: usize, seed: u64) -> String {
+        let count = size | 1;
+        let width = (usize::BITS - (2 * count).leading_zeros()).max(5) as usize;
+        let mut rng = Rng::new(seed);
+
+        // not every set of entries narrows down to exactly one of each rating (the co2
+        // candidates can all share the next bit), so keep drawing until one does
+        loop {
+            let mut seen = HashSet::new();
+            let mut entries = Vec::with_capacity(count);
+            while entries.len() < count {
+                let entry = rng.below(1 << width);
+                if seen.insert(entry) {
+                    entries.push(format!("{:0width$b}", entry, width = width));
+                }
+            }
+
+            let input = entries.join("\n");
+            if parse_input(&input).ok().is_some_and(|entries| part2(&entries).is_ok()) {
+                return input;
+            }
+        }
+    }
+}
+
+#[test]
+pub fn test_generate() {
+    use crate::part1;
+
+    for seed in 0..10 {
+        let entries = parse_input(&Day03::generate(200, seed)).unwrap();
+        assert_eq!(entries.len(), 201);
+        assert!(part1(&entries).is_ok());
+        assert!(part2(&entries).is_ok());
+    }
+}
@@ -0,0 +1,27 @@
+use common::{Generate, Rng};
+
+use crate::Day04;
+
+impl Generate for Day04 {
+    // `size` boards drawn from the numbers 0 to 99, all of which get called in a random
+    // order, so every board wins eventually
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut numbers = (0..100).collect::<Vec<u32>>();
+        let mut sections = Vec::with_capacity(size + 1);
+
+        rng.shuffle(&mut numbers);
+        sections.push(numbers.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(","));
+
+        for _ in 0..size {
+            rng.shuffle(&mut numbers);
+            let board = numbers[..25]
+                .chunks(5)
+                .map(|row| row.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>().join(" "))
+                .collect::<Vec<_>>();
+            sections.push(board.join("\n"));
+        }
+
+        sections.join("\n\n")
+    }
+}
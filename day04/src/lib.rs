@@ -0,0 +1,237 @@
+
+use std::fmt;
+use thiserror::Error;
+use common::{parse, trace, ParseError, Solution};
+
+pub mod generate;
+
+#[derive(PartialEq, Eq, Debug, Hash, Copy, Clone)]
+pub enum Status {
+    Unmarked,
+    Marked,
+}
+
+#[derive(PartialEq, Eq, Debug, Hash, Copy, Clone)]
+pub struct Entry {
+    status: Status,
+    value: u32,
+}
+
+#[derive(PartialEq, Eq, Debug, Hash, Clone)]
+pub struct Board<T> {
+    board: Vec<T>
+}
+
+impl<T> Board<T> where T : Copy + Clone {
+    pub fn rows(&self) -> Vec<Vec<T>>
+    {
+        (0..=4)
+            .map(|r| self
+                .board[(r*5) ..= ((r*5) + 4)].to_vec())
+            .collect()
+    }
+
+    pub fn columns(&self) -> Vec<Vec<T>>
+    {
+        (0..=4)
+            .map(|c| (0..=4).map(|r| self.board[(r*5) + c]).collect())
+            .collect()
+    }
+}
+
+impl Board<Entry> {
+    pub fn call_number(&mut self, number: u32) {
+        if let Some(entry) = self
+            .board
+            .iter_mut()
+            .find(|e| e.value == number) {
+                entry.status = Status::Marked;
+            }
+        
+    }
+
+    pub fn unmarked_sum(&self) -> u32 {
+        self.board.iter().filter(|e| e.status == Status::Unmarked).map(|e| e.value).sum()
+    }
+
+    pub fn is_win(&self) -> bool {
+        self.rows().iter().any(|r| r.iter().all(|e| e.status == Status::Marked))
+        || self.columns().iter().any(|c| c.iter().all(|e| e.status == Status::Marked))
+    }
+}
+
+impl Board<Entry> {
+    // `chunk` is a piece of `input`, which is just there so errors can say where they are
+    pub fn parse(input: &str, chunk: &str) -> Result<Self, ParseError> {
+        let numbers = chunk.split_whitespace().map(|n| parse::number(input, n)).collect::<Result<Vec<u32>, _>>()?;
+        if numbers.len() != 25 {
+            return Err(ParseError::at(input, chunk, format!("a 5x5 board, not {} numbers", numbers.len())));
+        }
+
+        Ok(Board { board: numbers.iter().map(|n| Entry { value: *n, status: Status::Unmarked }).collect() })
+    }
+}
+
+// marked numbers are the ones in brackets
+impl fmt::Display for Board<Entry> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for entry in row {
+                match entry.status {
+                    Status::Marked => write!(f, "[{:>2}]", entry.value)?,
+                    Status::Unmarked => write!(f, " {:>2} ", entry.value)?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+// all the boards still in play, one after another
+struct Boards<'a>(&'a [Board<Entry>]);
+
+impl fmt::Display for Boards<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, board) in self.0.iter().filter(|b| !b.is_win()).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", board)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Input {
+    numbers: Vec<u32>,
+    boards: Vec<Board<Entry>>,
+}
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let sections = parse::sections(input);
+    let (numbers, boards) = sections.split_first()
+        .ok_or_else(|| ParseError::at_end(input, "the numbers to draw"))?;
+    let numbers = parse::list(numbers, ",", |n| parse::number(input, n))?;
+    let boards = boards.iter().map(|b| Board::parse(input, b)).collect::<Result<Vec<_>, _>>()?;
+
+    if boards.is_empty() {
+        return Err(ParseError::at_end(input, "a blank line and then some boards"));
+    }
+
+    Ok(Input { numbers, boards })
+}
+
+pub fn winning_boards(input: &Input) -> Vec<u32> {
+    let mut input = input.clone();
+    let mut boards = Vec::new();
+    for (draw, number) in input.numbers.into_iter().enumerate() {
+        for board in input.boards.iter_mut() {
+            if !board.is_win() {
+                // this board's already won; never do it again
+                board.call_number(number);
+                if board.is_win() {
+                    boards.push(board.unmarked_sum() * number);
+                }
+            }
+        }
+
+        trace::snapshot(4, format_args!("draw {}: {} ({} boards won)", draw + 1, number, boards.len()), &Boards(&input.boards));
+    }
+
+    boards
+}
+
+// the numbers ran out before any row or column was all drawn
+#[derive(Error, Clone, Copy, PartialEq, Eq, Debug)]
+#[error("no board wins with the numbers drawn")]
+pub struct NoWinner;
+
+pub fn part_1(input: &Input) -> Result<u32, NoWinner> {
+    winning_boards(input).first().copied().ok_or(NoWinner)
+}
+
+pub fn part_2(input: &Input) -> Result<u32, NoWinner> {
+    winning_boards(input).last().copied().ok_or(NoWinner)
+}
+
+#[derive(Error, Debug)]
+pub enum SolutionError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    NoWinner(#[from] NoWinner),
+}
+
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Input;
+    type Output1 = u32;
+    type Output2 = u32;
+    type Error = SolutionError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part_1(input)?)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(part_2(input)?)
+    }
+}
+
+#[test]
+pub fn test_board() {
+    let board = Board { board: vec![22, 13, 17, 11, 0, 8, 2, 23, 4, 24, 21, 9, 14, 16, 7, 6, 10, 3, 18, 5, 1, 12, 20, 15, 19] };
+
+    let expected_rows = vec![
+        vec![22, 13, 17, 11, 0],
+        vec![8, 2, 23, 4, 24],
+        vec![21, 9, 14, 16, 7],
+        vec![6, 10, 3, 18, 5],
+        vec![1, 12, 20, 15, 19]
+    ];
+
+    let expected_columns = vec![
+        vec![22, 8, 21, 6, 1],
+        vec![13, 2, 9, 10, 12],
+        vec![17, 23, 14, 3, 20],
+        vec![11, 4, 16, 18, 15],
+        vec![0, 24, 7, 5, 19]
+    ];
+
+    assert_eq!(board.columns(), expected_columns);
+    assert_eq!(board.rows(), expected_rows);
+}
+
+#[test]
+pub fn test_trace() {
+    let input = parse_input("22,13,17,11,0\n\n22 13 17 11 0\n8 2 23 4 24\n21 9 14 16 7\n6 10 3 18 5\n1 12 20 15 19\n").unwrap();
+    let (scores, steps) = trace::record(|| winning_boards(&input));
+    assert_eq!(scores, vec![0]);
+    assert_eq!(steps[1].step, "draw 2: 13 (0 boards won)");
+    assert_eq!(steps[1].state.lines().next(), Some("[22][13] 17  11   0 "));
+    assert_eq!(steps[4].step, "draw 5: 0 (1 boards won)");
+    assert_eq!(steps[4].state, "");
+}
+
+#[test]
+pub fn test_no_winner() {
+    // four of the top row, and one from each of the others, never make a line
+    let input = parse_input("22,13,17,11,2,14,18,15\n\n22 13 17 11 0\n8 2 23 4 24\n21 9 14 16 7\n6 10 3 18 5\n1 12 20 15 19\n").unwrap();
+    assert_eq!(part_1(&input), Err(NoWinner));
+    assert_eq!(part_2(&input), Err(NoWinner));
+
+    let input = parse_input("22,13,17,11,0\n\n22 13 17 11 0\n8 2 23 4 24\n21 9 14 16 7\n6 10 3 18 5\n1 12 20 15 19\n").unwrap();
+    assert_eq!((part_1(&input), part_2(&input)), (Ok(0), Ok(0)));
+}
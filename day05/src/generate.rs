@@ -0,0 +1,31 @@
+use common::{Generate, Rng};
+
+use crate::Day05;
+
+impl Generate for Day05 {
+    // `size` vents on a 1000x1000 floor, about a third each horizontal, vertical and diagonal
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut lines = Vec::with_capacity(size);
+        for _ in 0..size {
+            let (x1, y1) = (rng.range(0..1000), rng.range(0..1000));
+            let (x2, y2) = match rng.index(3) {
+                0 => (rng.range(0..1000), y1),
+                1 => (x1, rng.range(0..1000)),
+                _ => {
+                    let dx = if rng.chance(0.5) { 1 } else { -1 };
+                    let dy = if rng.chance(0.5) { 1 } else { -1 };
+                    // don't run off the edge of the floor in either direction
+                    let room_x = if dx > 0 { 999 - x1 } else { x1 };
+                    let room_y = if dy > 0 { 999 - y1 } else { y1 };
+                    let length = rng.range(0..300).min(room_x).min(room_y);
+                    (x1 + dx * length, y1 + dy * length)
+                }
+            };
+
+            lines.push(format!("{},{} -> {},{}", x1, y1, x2, y2));
+        }
+
+        lines.join("\n")
+    }
+}
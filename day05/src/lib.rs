@@ -0,0 +1,143 @@
+use std::{cmp::{max, min}, collections::HashMap};
+use common::{parse, ParseError, Solution};
+use grid::image::{Image, Palette, Render};
+use grid::{Grid, Point};
+
+pub mod generate;
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct Coord {
+    x: u32,
+    y: u32,
+}
+
+impl Coord {
+    pub fn in_line_with(&self, other: &Coord) -> bool {
+        self.x == other.x || self.y == other.y
+    }
+
+    pub fn points_to(&self, other: &Coord) -> Vec<Coord> {
+        if self.x == other.x {
+            (min(self.y, other.y)..=max(self.y, other.y)).map(|y| Coord { x: self.x, y }).collect()
+        } else if self.y == other.y {
+            (min(self.x, other.x)..=max(self.x, other.x)).map(|x| Coord { x, y: self.y }).collect()
+        } else {
+            // must be diagonal
+            let x_diff = (self.x as i32) - (other.x as i32);
+            let y_diff = (self.y as i32) - (other.y as i32);
+            assert!(x_diff.abs() == y_diff.abs());
+
+            let x_increment = x_diff / x_diff.abs();
+            let y_increment = y_diff / y_diff.abs();
+
+            (0..=x_diff.abs()).map(|i| Coord { x: (self.x as i32 - i * x_increment) as u32, y: (self.y as i32 - i * y_increment) as u32 }).collect()
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Line {
+    start: Coord,
+    end: Coord,
+}
+
+impl Line {
+    pub fn points(&self) -> Vec<Coord> {
+        self.start.points_to(&self.end)
+    }
+}
+
+fn parse_coord(input: &str, s: &str) -> Result<Coord, ParseError> {
+    let [x, y] = parse::array(input, s, ",", "a point like `3,4`", |n| parse::number(input, n))?;
+    Ok(Coord { x, y })
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Line>, ParseError> {
+    parse::lines(input).map(|l| {
+        let (start, end) = parse::split_once(input, l, " -> ", "a line like `1,2 -> 3,4`")?;
+        let line = Line { start: parse_coord(input, start)?, end: parse_coord(input, end)? };
+        if !line.start.in_line_with(&line.end) && line.start.x.abs_diff(line.end.x) != line.start.y.abs_diff(line.end.y) {
+            return Err(ParseError::at(input, l, "a horizontal, vertical or 45 degree line"));
+        }
+
+        Ok(line)
+    }).collect()
+}
+
+pub fn resolve_lines<T : Iterator<Item = Line>>(input: T) -> HashMap<Coord, usize> {
+    let mut map = HashMap::new();
+
+    for line in input {
+        for p in line.points() {
+            *map.entry(p).or_insert(0) += 1;
+        }
+    }
+    map
+}
+
+pub fn part_1(input: &[Line]) -> usize {
+    let map = resolve_lines(input.iter().copied().filter(|l| l.start.in_line_with(&l.end)));
+    map.iter().filter(|(_, &count)| count >= 2).count()
+}
+
+pub fn part_2(input: &[Line]) -> usize {
+    let map = resolve_lines(input.iter().copied());
+    map.iter().filter(|(_, &count)| count >= 2).count()
+}
+
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Line>;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = ParseError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(part_2(input))
+    }
+}
+
+// how many vents are at each point, brighter where they overlap
+impl Render for Day05 {
+    fn render(input: &Self::Input) -> Result<Image, Self::Error> {
+        let vents = resolve_lines(input.iter().copied())
+            .into_iter()
+            .map(|(Coord { x, y }, count)| (Point { x: x as usize, y: y as usize }, count as u32));
+        Ok(Image::from_values(&Grid::from_sparse(vents, 0), Palette::Heat))
+    }
+}
+
+#[test]
+pub fn test_diagonals() {
+    let l1 = Line { start: Coord { x: 1, y: 1 }, end: Coord { x: 3, y: 3 } };
+    assert_eq!(l1.points(), vec![ Coord { x: 1, y: 1}, Coord { x: 2, y: 2 }, Coord { x: 3, y: 3 }]);
+
+    let l2 = Line { start: Coord { x: 9, y: 7 }, end: Coord { x: 7, y: 9 } };
+    assert_eq!(l2.points(), vec![ Coord { x: 9, y: 7}, Coord { x: 8, y: 8}, Coord { x: 7, y: 9 }])
+}
+#[test]
+pub fn test_parse_error() {
+    let error = parse_input("0,9 -> 5,9\n8,0 -> 0,x").unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 10, "x"));
+    assert_eq!(error.to_string(), "\
+error: expected a number, found `x`
+ --> line 2, column 10
+  |
+2 | 8,0 -> 0,x
+  |          ^");
+
+    let error = parse_input("0,9 -> 5,8").unwrap_err();
+    assert_eq!(error.expected, "a horizontal, vertical or 45 degree line");
+}
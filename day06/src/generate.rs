@@ -0,0 +1,25 @@
+use common::{Generate, Rng};
+
+use crate::Day06;
+
+impl Generate for Day06 {
+    // `size` fish, with timers between 1 and 5 like the real input
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        (0..size).map(|_| rng.range(1..6).to_string()).collect::<Vec<_>>().join(",")
+    }
+}
+
+#[test]
+pub fn test_generate() {
+    use common::Count;
+    use crate::{fish_after, fish_after_2, parse_input};
+
+    // the lookup table has to agree with actually simulating every fish
+    for seed in 0..10 {
+        let fish = parse_input(&Day06::generate(30, seed)).unwrap();
+        for days in [0, 1, 7, 9, 18, 40] {
+            assert_eq!(fish_after_2(&fish, days), Ok(Count::from(fish_after(&fish, days))));
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::fmt;
+use thiserror::Error;
+use common::{parse, trace, Count, Overflow, ParseError, Solution};
+
+pub mod generate;
+
+#[derive(Copy, Clone, Debug)]
+pub struct Lanternfish(u8);
+
+impl Lanternfish {
+    pub fn number_from_fish_after(&self, days: u32, lookup: &HashMap<u32, Count>) -> Count {
+        let days_till_next_spawn = self.0;
+        if days < days_till_next_spawn as u32 {
+            return Count::one();
+        }
+
+        let days = days - (days_till_next_spawn as u32);
+        lookup.get(&days).unwrap().clone()
+    }
+
+    pub fn live(&self) -> Vec<Lanternfish> {
+        if self.0 > 0 {
+            vec![Lanternfish(self.0 - 1)]
+        } else {
+            vec![Lanternfish(6), Lanternfish(8)]
+        }
+    }
+}
+
+// the timers of a whole school of fish, like the puzzle shows them: 3,4,3,1,2
+struct School<'a>(&'a [Lanternfish]);
+
+impl fmt::Display for School<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, fish) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", fish.0)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn build_number_from_scratch_after(days: u32) -> Result<HashMap<u32, Count>, Overflow> {
+    let mut numbers = HashMap::new();
+    // assume we are considering a single lanternfish with value 0
+    numbers.insert(0, Count::one());
+    for d in 1..=7 {
+        numbers.insert(d, Count::from(2u64));
+    }
+
+    numbers.insert(8, Count::from(3u64));
+    numbers.insert(9, Count::from(3u64));
+
+    for d in 10..=days {
+        let seven_days_ago = numbers.get(&(d - 7)).unwrap();
+        let nine_days_ago = numbers.get(&(d - 9)).unwrap();
+        numbers.insert(d, seven_days_ago.checked_add(nine_days_ago)?);
+    }
+
+    Ok(numbers)
+}
+
+pub fn fish_after(starting_fish: &[Lanternfish], days: u32) -> usize {
+    let mut pool = starting_fish.to_vec();
+    for day in 1..=days {
+        pool = pool.iter().flat_map(|f| f.live()).collect();
+        trace::snapshot(6, format_args!("after {} days ({} fish)", day, pool.len()), &School(&pool));
+    }
+
+    pool.len()
+}
+
+pub fn fish_after_2(starting_fish: &[Lanternfish], days: u32) -> Result<Count, Overflow> {
+    let lookup = build_number_from_scratch_after(days)?;
+    let fish = starting_fish.iter().map(|f| f.number_from_fish_after(days, &lookup)).collect::<Vec<_>>();
+    Count::sum(&fish)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Lanternfish>, ParseError> {
+    parse::list(input.trim(), ",", |i| match parse::number(input, i)? {
+        timer @ 0..=8 => Ok(Lanternfish(timer)),
+        _ => Err(ParseError::at(input, i, "a timer from 0 to 8")),
+    })
+}
+
+
+#[derive(Error, Debug)]
+pub enum SolutionError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Lanternfish>;
+    type Output1 = usize;
+    type Output2 = Count;
+    type Error = SolutionError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(fish_after(input, 80))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(fish_after_2(input, 256)?)
+    }
+}
+
+#[test]
+pub fn test_fish() {
+    let start = parse_input("3,4,3,1,2").unwrap();
+    let (fish, steps) = trace::record(|| fish_after(&start, 18));
+    assert_eq!(fish, 26);
+    assert_eq!(steps[0].state, "2,3,2,0,1");
+    assert_eq!(steps[17].step, "after 18 days (26 fish)");
+    assert_eq!(fish_after(&start, 80), 5934);
+
+    assert_eq!(fish_after_2(&start, 18), Ok(Count::from(26u64)));
+    assert_eq!(fish_after_2(&start, 80), Ok(Count::from(5934u64)));
+    assert_eq!(fish_after_2(&start, 256), Ok(Count::from(26984457539u64)));
+
+    // the number of fish roughly doubles every week, so a u64 gives up a bit after 400 days
+    if common::count::UNBOUNDED {
+        assert!(fish_after_2(&start, 500).unwrap() > Count::from(u64::MAX));
+    } else {
+        assert_eq!(fish_after_2(&start, 500), Err(Overflow));
+    }
+}
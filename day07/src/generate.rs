@@ -0,0 +1,27 @@
+use common::{Generate, Rng};
+
+use crate::Day07;
+
+impl Generate for Day07 {
+    // `size` crabs between 0 and 1000. part 2's fuel costs fit in a u32 for up to about
+    // eight thousand of them
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        (0..size).map(|_| rng.range(0..1001).to_string()).collect::<Vec<_>>().join(",")
+    }
+}
+
+#[test]
+pub fn test_generate() {
+    use crate::{find_optimal_fuel_cost, parse_input};
+
+    // with a linear cost the best position is the median
+    for seed in 0..10 {
+        let submarines = parse_input(&Day07::generate(101, seed)).unwrap();
+        let mut positions = submarines.iter().map(|s| s.0).collect::<Vec<_>>();
+        positions.sort_unstable();
+        let median = positions[positions.len() / 2];
+        let expected = positions.iter().map(|p| p.abs_diff(median)).sum::<u32>();
+        assert_eq!(find_optimal_fuel_cost(&submarines, |d| d), expected);
+    }
+}
@@ -0,0 +1,53 @@
+use common::{parse, ParseError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub mod generate;
+
+pub struct Submarine(u32);
+
+pub fn parse_input(input: &str) -> Result<Vec<Submarine>, ParseError> {
+    parse::list(input.trim(), ",", |i| Ok(Submarine(parse::number(input, i)?)))
+}
+
+pub fn find_optimal_fuel_cost(submarines: &[Submarine], cost_function: fn(u32) -> u32) -> u32 {
+    let min_coordinate = submarines.iter().map(|s| s.0).min().unwrap();
+    let max_coordinate = submarines.iter().map(|s| s.0).max().unwrap();
+
+    let candidates = min_coordinate..=max_coordinate;
+    #[cfg(feature = "parallel")]
+    let candidates = candidates.into_par_iter();
+
+    candidates
+        .map(|c| submarines.iter().map(|s| cost_function(c.abs_diff(s.0))).sum::<u32>())
+        .min()
+        .unwrap()
+}
+
+pub fn crab_cost(distance: u32) -> u32 {
+    distance * (distance + 1) / 2
+}
+
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Submarine>;
+    type Output1 = u32;
+    type Output2 = u32;
+    type Error = ParseError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(find_optimal_fuel_cost(input, |d| d))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(find_optimal_fuel_cost(input, crab_cost))
+    }
+}
@@ -0,0 +1,40 @@
+use common::{Generate, Rng};
+
+use crate::Day08;
+
+// the segments lit for each digit on a correctly wired display
+const DIGITS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+// the digit as it appears on a display wired up by `wiring`, with its segments in any order
+fn pattern(rng: &mut Rng, wiring: &[char], digit: usize) -> String {
+    let mut segments = DIGITS[digit]
+        .chars()
+        .map(|s| wiring[(s as u8 - b'a') as usize])
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut segments);
+    segments.into_iter().collect()
+}
+
+impl Generate for Day08 {
+    // `size` displays, each wired up differently
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut entries = Vec::with_capacity(size);
+        for _ in 0..size {
+            let mut wiring = "abcdefg".chars().collect::<Vec<_>>();
+            rng.shuffle(&mut wiring);
+
+            let mut digits = (0..10).collect::<Vec<_>>();
+            rng.shuffle(&mut digits);
+            let patterns = digits.iter().map(|&d| pattern(&mut rng, &wiring, d)).collect::<Vec<_>>();
+            let output = (0..4).map(|_| {
+                let digit = rng.index(10);
+                pattern(&mut rng, &wiring, digit)
+            }).collect::<Vec<_>>();
+
+            entries.push(format!("{} | {}", patterns.join(" "), output.join(" ")));
+        }
+
+        entries.join("\n")
+    }
+}
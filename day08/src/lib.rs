@@ -0,0 +1,279 @@
+use std::collections::{HashSet, HashMap};
+use common::{parse, ParseError, Solution};
+
+pub mod generate;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Segment {
+    A,B,C,D,E,F,G,
+}
+
+impl Segment {
+    pub fn all_segments() -> HashSet<Segment> {
+        vec![Segment::A, Segment::B, Segment::C, Segment::D, Segment::E, Segment::F, Segment::G].into_iter().collect()
+    }
+
+    pub fn zero() -> HashSet<Segment> {
+        vec![Segment::A, Segment::B, Segment::C, Segment::E, Segment::F, Segment::G].into_iter().collect()
+    }
+
+    pub fn one() -> HashSet<Segment> {
+        vec![Segment::C, Segment::F].into_iter().collect()
+    }
+
+    pub fn two() -> HashSet<Segment> {
+        vec![Segment::A, Segment::C, Segment::D, Segment::E, Segment::G].into_iter().collect()
+    }
+
+    pub fn three() -> HashSet<Segment> {
+        vec![Segment::A, Segment::C, Segment::D, Segment::F, Segment::G].into_iter().collect()
+    }
+
+    pub fn four() -> HashSet<Segment> {
+        vec![Segment::B, Segment::C, Segment::D, Segment::F].into_iter().collect()
+    }
+
+    pub fn five() -> HashSet<Segment> {
+        vec![Segment::A, Segment::B, Segment::D, Segment::F, Segment::G].into_iter().collect()
+    }
+
+    pub fn six() -> HashSet<Segment> {
+        vec![Segment::A, Segment::B, Segment::D, Segment::E, Segment::F, Segment::G].into_iter().collect()
+    }
+
+    pub fn seven() -> HashSet<Segment> {
+        vec![Segment::A, Segment::C, Segment::F].into_iter().collect()
+    }
+
+    pub fn eight() -> HashSet<Segment> {
+        Self::all_segments()
+    }
+
+    pub fn nine() -> HashSet<Segment> {
+        vec![Segment::A, Segment::B, Segment::C, Segment::D, Segment::F, Segment::G].into_iter().collect()
+    }
+}
+
+impl TryFrom<char> for Segment {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'a' => Ok(Self::A),
+            'b' => Ok(Self::B),
+            'c' => Ok(Self::C),
+            'd' => Ok(Self::D),
+            'e' => Ok(Self::E),
+            'f' => Ok(Self::F),
+            'g' => Ok(Self::G),
+            _ => Err(())
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SignalPattern {
+    pattern: HashSet<Segment>
+}
+
+impl SignalPattern {
+    pub fn is_easy_digit(&self) -> bool {
+        self.pattern.len() == 2
+        || self.pattern.len() == 3
+        || self.pattern.len() == 4
+        || self.pattern.len() == 7
+    }
+}
+
+impl SignalPattern {
+    // `value` is a piece of `input`, which is just there so errors can say where they are
+    pub fn parse(input: &str, value: &str) -> Result<Self, ParseError> {
+        let pattern = parse::chars(input, value, "a segment from `a` to `g`", |c| Segment::try_from(c).ok())?;
+        Ok(Self { pattern: pattern.into_iter().collect() })
+    }
+
+    fn parse_all(input: &str, values: &str, count: usize) -> Result<Vec<Self>, ParseError> {
+        let patterns = values.split_whitespace().map(|v| Self::parse(input, v)).collect::<Result<Vec<_>, _>>()?;
+        if patterns.len() != count {
+            return Err(ParseError::at(input, values.trim(), format!("{} patterns, not {}", count, patterns.len())));
+        }
+
+        Ok(patterns)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Entry {
+    // ten of these
+    patterns: Vec<SignalPattern>,
+    // four of these
+    values: Vec<SignalPattern>,
+}
+
+impl Entry {
+    pub fn decode_output(&self) -> u32 {
+        let mut mappings: HashMap<_, _> = Segment::all_segments()
+        .into_iter()
+            .map(|s| (s, Segment::all_segments()))
+            .collect();
+
+        let all_patterns: Vec<_> = self.patterns.iter().chain(self.values.iter()).collect();
+
+        for p in all_patterns {
+            if p.pattern.len() == 2 {
+                // this is a 1
+                for s in p.pattern.iter() {
+                    mappings.entry(*s).and_modify(|options| {
+                        let intersection: HashSet<Segment> = options.intersection(&Segment::one()).copied().collect();
+                        *options = intersection;
+                    });
+                }
+            }
+
+            if p.pattern.len() == 3 {
+                // this is a 7
+                for s in p.pattern.iter() {
+                    mappings.entry(*s).and_modify(|options| {
+                        let intersection: HashSet<Segment> = options.intersection(&Segment::seven()).copied().collect();
+                        *options = intersection;
+                    });
+                }
+            }
+
+            if p.pattern.len() == 4 {
+                // this is a 4
+                for s in p.pattern.iter() {
+                    mappings.entry(*s).and_modify(|options| {
+                        let intersection: HashSet<Segment> = options.intersection(&Segment::four()).copied().collect();
+                        *options = intersection;
+                    });
+                }
+            }
+
+            if p.pattern.len() == 5 {
+                // this is a 2, 3, or 5 - must have A/D/G in the mapping somewhere
+                // so remove A, D, and G from the options of what's not in this pattern
+                for s in Segment::all_segments().difference(&p.pattern) {
+                    mappings.entry(*s).and_modify(|options| {
+                        options.remove(&Segment::A);
+                        options.remove(&Segment::D);
+                        options.remove(&Segment::G);
+                    });
+                }
+            }
+
+            if p.pattern.len() == 6 {
+                // this is a 0, 6, or 9, so the missing segment must be either D, C, or E
+                let missing_segments = vec![Segment::C, Segment::D, Segment::E].into_iter().collect::<HashSet<_>>();
+                for s in Segment::all_segments().difference(&p.pattern) {
+                    mappings.entry(*s).and_modify(|options| {
+                        let intersection: HashSet<Segment> = options.intersection(&missing_segments).copied().collect();
+                        *options = intersection;
+                    });
+                }
+            }
+        }
+
+        let mut progressed = false;
+        loop {
+            if mappings.iter().all(|(_, v)| v.len() == 1) {
+                // found our mappings
+                break;
+            }
+
+            let resolved: Vec<_> = mappings.clone().into_iter().filter(|(_, v)| v.len() == 1).collect();
+            for (k, v) in resolved {
+                for (k2, v2) in mappings.iter_mut() {
+                    if *k2 != k {
+                        progressed |= v2.remove(v.iter().next().unwrap());
+                    }
+                }
+            }
+
+            if !progressed {
+                panic!()
+            }
+        }
+
+        let mut digits = String::new();
+        for pattern in self.values.iter() {
+            let number = pattern.pattern.iter().map(|s| *mappings.get(s).unwrap().iter().next().unwrap()).collect::<HashSet<_>>();
+            if number == Segment::one() {
+                digits.push('1');
+            } else if number == Segment::two() {
+                digits.push('2');
+            } else if number == Segment::three() {
+                digits.push('3');
+            } else if number == Segment::four() {
+                digits.push('4');
+            } else if number == Segment::five() {
+                digits.push('5');
+            } else if number == Segment::six() {
+                digits.push('6');
+            } else if number == Segment::seven() {
+                digits.push('7');
+            } else if number == Segment::eight() {
+                digits.push('8');
+            } else if number == Segment::nine() {
+                digits.push('9');
+            } else if number == Segment::zero() {
+                digits.push('0')
+            }
+        }
+
+        digits.parse().unwrap()
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Entry>, ParseError> {
+    let mut entries = Vec::new();
+    for l in parse::lines(input) {
+        let (patterns, outputs) = parse::split_once(input, l, "|", "ten patterns, `|` and then four more")?;
+        entries.push(
+            Entry {
+                patterns: SignalPattern::parse_all(input, patterns, 10)?,
+                values: SignalPattern::parse_all(input, outputs, 4)?,
+            });
+    }
+
+    Ok(entries)
+}
+
+pub fn part_1(entries: &[Entry]) -> usize {
+    entries.iter().map(|e| e.values.iter().filter(|p| p.is_easy_digit()).count()).sum()
+}
+
+pub fn part_2(entries: &[Entry]) -> u32 {
+    entries.iter().map(|e| e.decode_output()).sum()
+}
+
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Entry>;
+    type Output1 = usize;
+    type Output2 = u32;
+    type Error = ParseError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(part_2(input))
+    }
+}
+
+#[test]
+pub fn test() {
+    let single_entry = parse_input("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab |    cdfeb fcadb cdfeb cdbaf").unwrap();
+
+    assert_eq!(single_entry.first().unwrap().decode_output(), 5353);
+}
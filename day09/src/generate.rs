@@ -0,0 +1,20 @@
+use common::{Generate, Rng};
+
+use crate::Day09;
+
+// a `width` by `height` heightmap, with about a quarter of it 9s so it splits up into basins
+pub fn heightmap(width: usize, height: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let rows = (0..height).map(|_| {
+        (0..width).map(|_| if rng.chance(0.25) { 9 } else { rng.range(0..9) }.to_string()).collect::<String>()
+    }).collect::<Vec<_>>();
+
+    rows.join("\n")
+}
+
+impl Generate for Day09 {
+    // a square heightmap `size` on each side
+    fn generate(size: usize, seed: u64) -> String {
+        heightmap(size, size, seed)
+    }
+}
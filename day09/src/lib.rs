@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use common::{ParseError, Solution};
+use grid::image::{Image, Render, Rgb};
+use grid::Grid;
+
+pub mod generate;
+
+pub fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    let heights = Grid::parse_digits(input)?;
+    if heights.is_empty() {
+        return Err(ParseError::at_end(input, "at least one row of heights"));
+    }
+
+    Ok(heights)
+}
+
+pub fn part_1(input: &Grid<u32>) -> u32 {
+    let mut low_points = Vec::new();
+    for (p, height) in input.iter() {
+        if input.neighbours(p).all(|n| input[n] > *height) {
+            low_points.push(1 + height);
+        }
+    }
+
+    low_points.into_iter().sum()
+}
+
+// which basin each point ends up in, if any (the 9s don't belong to one)
+pub fn label_basins(input: &Grid<u32>) -> Grid<Option<u32>> {
+    let mut basin_mappings = input.map(|_| None);
+    let mut next_basin: u32 = 1;
+    for (start, height) in input.iter() {
+        if *height == 9 || basin_mappings[start].is_some() {
+            // 9s don't belong to any basin, and we've already filled in this one
+            continue;
+        }
+
+        // flood out from here until we hit 9s (or the edge) all the way round
+        let mut to_visit = vec![start];
+        basin_mappings[start] = Some(next_basin);
+        while let Some(p) = to_visit.pop() {
+            for n in input.neighbours(p) {
+                if input[n] != 9 && basin_mappings[n].is_none() {
+                    basin_mappings[n] = Some(next_basin);
+                    to_visit.push(n);
+                }
+            }
+        }
+
+        next_basin += 1;
+    }
+
+    basin_mappings
+}
+
+pub fn calculate_basins(input: &Grid<u32>) -> HashMap<u32, Vec<u32>> {
+    // basin number -> collection of heights in the basin
+    let mut basins = HashMap::new();
+    for (p, basin) in label_basins(input).iter() {
+        if let Some(basin) = basin {
+            basins.entry(*basin).or_insert_with(Vec::new).push(input[p]);
+        }
+    }
+
+    basins
+}
+
+pub fn part_2(input: &Grid<u32>) -> usize {
+    let basins = calculate_basins(input);
+    let mut sizes = basins.values().map(|v| v.len()).collect::<Vec<_>>();
+    sizes.sort_unstable();
+    sizes.into_iter().rev().take(3).product::<usize>()
+}
+
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Grid<u32>;
+    type Output1 = u32;
+    type Output2 = usize;
+    type Error = ParseError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(part_2(input))
+    }
+}
+
+// every basin in its own colour, with the 9s around them in black
+impl Render for Day09 {
+    fn render(input: &Self::Input) -> Result<Image, Self::Error> {
+        Ok(Image::from_grid(&label_basins(input), |basin| basin.map_or(Rgb::BLACK, |b| Rgb::distinct(b as usize))))
+    }
+}
+
+#[test]
+pub fn test() {
+    let input = include_str!("../fixtures/example.txt");
+
+    let input = parse_input(input).unwrap();
+    assert_eq!(calculate_basins(&input).len(), 4);
+
+    let image = Day09::render(&input).unwrap();
+    let colours = (0..10).map(|x| image.pixel(grid::Point { x, y: 0 }).unwrap()).collect::<Vec<_>>();
+    assert_eq!(colours[2], Rgb::BLACK);
+    assert_eq!(colours[0], colours[1]);
+    assert_ne!(colours[0], colours[9]);
+}
+
+#[test]
+pub fn test_empty() {
+    for input in ["", "\n", " \n\n"] {
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(error.expected, "at least one row of heights");
+    }
+}
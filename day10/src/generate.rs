@@ -0,0 +1,62 @@
+use common::{Generate, Rng};
+
+use crate::Day10;
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+// completion scores are base 5, so anything much deeper than this overflows a u64
+const MAX_DEPTH: usize = 20;
+
+// a line of chunks that's either corrupted, or incomplete but otherwise fine
+fn line(rng: &mut Rng, corrupt: bool) -> String {
+    let length = rng.range(20..110) as usize;
+    let mut open = Vec::new();
+    let mut line = String::new();
+    while line.len() < length || open.is_empty() {
+        match open.last() {
+            Some(&closer) if open.len() == MAX_DEPTH || rng.chance(0.4) => {
+                open.pop();
+                line.push(closer);
+            }
+            _ => {
+                let (opener, closer) = *rng.pick(&PAIRS);
+                open.push(closer);
+                line.push(opener);
+            }
+        }
+    }
+
+    if corrupt {
+        let expected = *open.last().unwrap();
+        let (_, wrong) = *rng.pick(&PAIRS.iter().filter(|(_, c)| *c != expected).copied().collect::<Vec<_>>());
+        line.push(wrong);
+    }
+
+    line
+}
+
+impl Generate for Day10 {
+    // `size` lines, roughly half of them corrupted and the rest incomplete. the first is
+    // always incomplete, since part 2 needs at least one of those
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let lines = (0..size).map(|i| {
+            let corrupt = i > 0 && rng.chance(0.5);
+            line(&mut rng, corrupt)
+        }).collect::<Vec<_>>();
+
+        lines.join("\n")
+    }
+}
+
+#[test]
+pub fn test_generate() {
+    use crate::{process_line, LineStatus};
+
+    let input = Day10::generate(200, 3);
+    let statuses = input.lines().map(process_line).collect::<Vec<_>>();
+    assert_eq!(statuses.len(), 200);
+    assert!(matches!(statuses[0], LineStatus::Incomplete { .. }));
+    assert!(statuses.iter().all(|s| *s != LineStatus::Okay));
+    assert!(statuses.iter().any(|s| matches!(s, LineStatus::Corrupt { .. })));
+}
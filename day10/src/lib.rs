@@ -0,0 +1,232 @@
+use thiserror::Error;
+use common::{parse, ParseError, Solution};
+
+pub mod generate;
+
+#[derive(PartialEq, Eq, Debug, Hash, Copy, Clone)]
+pub enum Symbol {
+    OpenParen,
+    CloseParen,
+    OpenBracket,
+    CloseBracket,
+    OpenBrace,
+    CloseBrace,
+    OpenAngle,
+    CloseAngle
+}
+
+impl Symbol {
+    pub fn score(&self) -> u32 {
+        match self {
+            Symbol::OpenParen => todo!(),
+            Symbol::CloseParen => 3,
+            Symbol::OpenBracket => todo!(),
+            Symbol::CloseBracket => 57,
+            Symbol::OpenBrace => todo!(),
+            Symbol::CloseBrace => 1197,
+            Symbol::OpenAngle => todo!(),
+            Symbol::CloseAngle => 25137,
+        }
+    }
+
+    pub fn points(&self) -> u32 {
+        match self {
+            Symbol::OpenParen => todo!(),
+            Symbol::CloseParen => 1,
+            Symbol::OpenBracket => todo!(),
+            Symbol::CloseBracket => 2,
+            Symbol::OpenBrace => todo!(),
+            Symbol::CloseBrace => 3,
+            Symbol::OpenAngle => todo!(),
+            Symbol::CloseAngle => 4,
+        }
+    }
+
+    pub fn pair(&self) -> Symbol {
+        match self {
+            Symbol::OpenParen => Symbol::CloseParen,
+            Symbol::CloseParen => Symbol::OpenParen,
+            Symbol::OpenBracket => Symbol::CloseBracket,
+            Symbol::CloseBracket => Symbol::OpenBracket,
+            Symbol::OpenBrace => Symbol::CloseBrace,
+            Symbol::CloseBrace => Symbol::OpenBrace,
+            Symbol::OpenAngle => Symbol::CloseAngle,
+            Symbol::CloseAngle => Symbol::OpenAngle,
+        }
+    }
+}
+
+impl TryFrom<char> for Symbol {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '(' => Ok(Self::OpenParen),
+            ')' => Ok(Self::CloseParen),
+            '[' => Ok(Self::OpenBracket),
+            ']' => Ok(Self::CloseBracket),
+            '{' => Ok(Self::OpenBrace),
+            '}' => Ok(Self::CloseBrace),
+            '<' => Ok(Self::OpenAngle),
+            '>' => Ok(Self::CloseAngle),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub enum LineStatus {
+    Okay,
+    Incomplete { completion: Vec<Symbol> },
+    Corrupt { unexpected_symbol: Symbol }
+}
+
+pub fn process_line(line: &str) -> LineStatus {
+    let mut stack = Vec::new();
+    for c in line.chars() {
+        let symbol = Symbol::try_from(c).unwrap();
+        match symbol {
+            Symbol::OpenParen 
+            | Symbol::OpenBracket 
+            | Symbol::OpenBrace 
+            | Symbol::OpenAngle => stack.push(symbol),
+            Symbol::CloseParen 
+            | Symbol::CloseBracket
+            | Symbol::CloseBrace
+            | Symbol::CloseAngle => match stack.pop() {
+                    Some(s) if s == symbol.pair() => (),
+                    _ => return LineStatus::Corrupt { unexpected_symbol: symbol },
+                },
+        }
+    }
+
+    if stack.is_empty() {
+        LineStatus::Okay
+    } else {
+        let mut completion = Vec::new();
+        while let Some(s) = stack.pop() {
+            completion.push(s.pair());
+        }
+
+        LineStatus::Incomplete { completion }
+    }
+}
+
+pub fn line_score(line: &str) -> u64 {
+    if let LineStatus::Incomplete { completion: c } = process_line(line) {
+        let mut score = 0;
+        for s in c {
+            score *= 5;
+            score += s.points() as u64;
+        }
+
+        return score;
+    }
+
+    0
+}
+
+// the parts work straight off the lines, so this only checks there's nothing in there but
+// brackets (and tidies up the line endings)
+pub fn parse_input(input: &str) -> Result<String, ParseError> {
+    let lines = parse::lines(input).map(|line| {
+        parse::chars(input, line, "one of `()[]{}<>`", |c| Symbol::try_from(c).ok())?;
+        Ok(line)
+    }).collect::<Result<Vec<_>, ParseError>>()?;
+    if lines.is_empty() {
+        return Err(ParseError::at_end(input, "at least one line of brackets"));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+pub fn part_1(input: &str) -> u32 {
+    let lines = input.lines().map(process_line);
+    let mut result = 0;
+    for l in lines {
+        if let LineStatus::Corrupt { unexpected_symbol: s } = l {
+            result += s.score();
+        }
+    }
+
+    result
+}
+
+// every line was either corrupt or complete, so there's no middle score to pick
+#[derive(Error, Clone, Copy, PartialEq, Eq, Debug)]
+#[error("none of the lines are incomplete, so there's nothing to score")]
+pub struct NoIncomplete;
+
+pub fn part_2(input: &str) -> Result<u64, NoIncomplete> {
+    // think it's safe to ignore the zeros?
+    let mut lines = input.lines()
+        .map(line_score)
+        .filter(|s| *s != 0)
+        .collect::<Vec<_>>();
+    lines.sort();
+    lines.get(lines.len() / 2).copied().ok_or(NoIncomplete)
+}
+
+#[derive(Error, Debug)]
+pub enum SolutionError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    NoIncomplete(#[from] NoIncomplete),
+}
+
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = String;
+    type Output1 = u32;
+    type Output2 = u64;
+    type Error = SolutionError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(part_2(input)?)
+    }
+}
+
+#[test]
+pub fn test_line_status() {
+    assert_eq!(process_line("{([(<{}[<>[]}>{[]{[(<()>"), LineStatus::Corrupt { unexpected_symbol: Symbol::CloseBrace });
+    assert_eq!(process_line("[[<[([]))<([[{}[[()]]]"), LineStatus::Corrupt { unexpected_symbol: Symbol::CloseParen });
+    assert_eq!(process_line("[{[{({}]{}}([{[{{{}}([]"), LineStatus::Corrupt { unexpected_symbol: Symbol::CloseBracket });
+    assert_eq!(process_line("[<(<(<(<{}))><([]([]()"), LineStatus::Corrupt { unexpected_symbol: Symbol::CloseParen });
+    assert_eq!(process_line("<{([([[(<>()){}]>(<<{{"), LineStatus::Corrupt { unexpected_symbol: Symbol::CloseAngle });
+
+    assert_eq!(line_score("[({(<(())[]>[[{[]{<()<>>"), 288_957);
+    assert_eq!(line_score("[(()[<>])]({[<{<<[]>>("), 5566);
+    assert_eq!(line_score("(((({<>}<{<{<>}{[]{[]{}"), 1_480_781);
+    assert_eq!(line_score("{<[[]]>}<{[{[{[]{()[[[]"), 995_444);
+    assert_eq!(line_score("<{([{{}}[<[[[<>{}]]]>[]]"), 294);
+}
+
+#[test]
+pub fn test_empty() {
+    for input in ["", "\n", " \n\n"] {
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(error.expected, "at least one line of brackets");
+    }
+}
+
+#[test]
+pub fn test_no_incomplete() {
+    for input in ["(]", "()", "(]\n[]\n"] {
+        let input = parse_input(input).unwrap();
+        assert_eq!(part_2(&input), Err(NoIncomplete));
+    }
+    assert_eq!(part_2(&parse_input("(]\n(\n").unwrap()), Ok(1));
+}
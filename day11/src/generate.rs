@@ -0,0 +1,35 @@
+use common::{Generate, Rng};
+
+use crate::{parse_input, Day11};
+
+// how long to give a grid to flash all at once before trying another one
+const PATIENCE: usize = 1000;
+
+impl Generate for Day11 {
+    // a square grid of octopuses `size` on each side. plenty of random grids never flash all
+    // together, and part 2 would spin forever on those, so keep drawing until one does. that
+    // gets rarer as the grid gets bigger, so stick to something near the real 10x10
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        loop {
+            let rows = (0..size).map(|_| {
+                (0..size).map(|_| rng.range(0..10).to_string()).collect::<String>()
+            }).collect::<Vec<_>>();
+
+            let input = rows.join("\n");
+            let mut octopuses = parse_input(&input).unwrap();
+            if (0..PATIENCE).any(|_| octopuses.step() == octopuses.len()) {
+                return input;
+            }
+        }
+    }
+}
+
+#[test]
+pub fn test_generate() {
+    use crate::part_2;
+
+    for seed in 0..3 {
+        assert!(part_2(&parse_input(&Day11::generate(10, seed)).unwrap()) <= PATIENCE);
+    }
+}
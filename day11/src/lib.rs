@@ -0,0 +1,166 @@
+use common::{trace, ParseError, Solution};
+use grid::image::{Image, Palette, Render};
+use grid::Grid;
+
+pub mod generate;
+
+#[derive(Clone, Debug)]
+pub struct Octopuses {
+    energy: Grid<u32>,
+    steps: usize,
+}
+
+impl Octopuses {
+    pub fn step(&mut self) -> usize {
+        let mut flashed_this_step = self.energy.map(|_| false);
+        // first, the energy level of each octopus increases by 1
+        for energy in self.energy.values_mut() {
+            *energy += 1;
+        }
+
+        let mut ready_to_flash = self.energy.iter().filter(|(_, &e)| e > 9).map(|(o, _)| o).collect::<Vec<_>>();
+        let mut flashes = 0;
+
+        // then, repeat this until we run out of octopuses that can flash
+        while let Some(octopus) = ready_to_flash.pop() {
+            if flashed_this_step[octopus] {
+                continue;
+            }
+
+            // remember that this octopus flashed
+            flashed_this_step[octopus] = true;
+            flashes += 1;
+
+            for neighbour in self.energy.neighbours_diagonal(octopus) {
+                self.energy[neighbour] += 1;
+                if self.energy[neighbour] > 9 && !flashed_this_step[neighbour] {
+                    ready_to_flash.push(neighbour);
+                }
+            }
+        }
+
+        // then, set the energy of any octopus that flashed this step to 0
+        for energy in self.energy.values_mut() {
+            if *energy > 9 {
+                *energy = 0;
+            }
+        }
+
+        self.steps += 1;
+        trace::snapshot(11, format_args!("step {} ({} flashes)", self.steps, flashes), &self.energy);
+        flashes
+    }
+
+    pub fn len(&self) -> usize {
+        self.energy.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.energy.is_empty()
+    }
+
+}
+
+pub fn parse_input(input: &str) -> Result<Octopuses, ParseError> {
+    let energy = Grid::parse_digits(input)?;
+    if energy.is_empty() {
+        return Err(ParseError::at_end(input, "at least one row of octopuses"));
+    }
+
+    Ok(Octopuses { energy, steps: 0 })
+}
+
+pub fn part_1(grid: &Octopuses) -> usize {
+    let mut grid = grid.clone();
+    let mut flashes = 0;
+    for _ in 0..100 {
+        flashes += grid.step();
+    }
+
+    flashes
+}
+
+pub fn part_2(grid: &Octopuses) -> usize {
+    let mut grid = grid.clone();
+    for step in 1.. {
+        if grid.step() == grid.len() {
+            return step;
+        }
+    }
+
+    unreachable!()
+}
+
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Octopuses;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = ParseError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(part_2(input))
+    }
+}
+
+// how often each octopus flashed over the first 100 steps
+impl Render for Day11 {
+    fn render(input: &Self::Input) -> Result<Image, Self::Error> {
+        let mut grid = input.clone();
+        let mut flashes = input.energy.map(|_| 0u32);
+        for _ in 0..100 {
+            grid.step();
+            for (octopus, &energy) in grid.energy.iter() {
+                // everything that flashed has just been reset
+                if energy == 0 {
+                    flashes[octopus] += 1;
+                }
+            }
+        }
+
+        Ok(Image::from_values(&flashes, Palette::Heat))
+    }
+}
+
+#[test]
+pub fn test_evolve() {
+    let input = include_str!("../fixtures/example.txt");
+
+    let mut grid = parse_input(input).unwrap();
+    let (flashes, steps) = trace::record(|| (0..100).map(|_| grid.step()).sum::<usize>());
+    assert_eq!(flashes, 1656);
+    assert_eq!(steps.len(), 100);
+    assert_eq!(steps[1].step, "step 2 (35 flashes)");
+    assert_eq!(steps[1].state, "\
+8807476555
+5089087054
+8597889608
+8485769600
+8700908800
+6600088989
+6800005943
+0000007456
+9000000876
+8700006848
+");
+}
+
+#[test]
+pub fn test_empty() {
+    for input in ["", "\n", " \n\n"] {
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(error.expected, "at least one row of octopuses");
+    }
+}
@@ -0,0 +1,71 @@
+use common::{Generate, Rng};
+
+use crate::Day12;
+
+fn is_big(cave: &str) -> bool {
+    cave.chars().all(|c| c.is_uppercase())
+}
+
+// a connected cave system with `caves` caves besides start and end, and about half as many
+// again extra passages on top of the ones that hold it together. big caves never join onto
+// each other, since there'd be infinitely many paths otherwise
+pub fn cave_system(caves: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut names = vec!["start".to_owned()];
+    while names.len() <= caves {
+        let letters = (0..2).map(|_| (b'a' + rng.index(26) as u8) as char).collect::<String>();
+        let name = if rng.chance(0.3) { letters.to_uppercase() } else { letters };
+        if !names.iter().any(|n| n.eq_ignore_ascii_case(&name)) && name != "end" {
+            names.push(name);
+        }
+    }
+    names.push("end".to_owned());
+
+    let mut passages: Vec<(usize, usize)> = Vec::new();
+    let allowed = |passages: &[(usize, usize)], a: usize, b: usize| {
+        let both_big = is_big(&names[a]) && is_big(&names[b]);
+        a != b && !both_big && !passages.contains(&(a, b)) && !passages.contains(&(b, a))
+    };
+
+    // join every cave onto one we've already joined up; start is small, so there's always
+    // somewhere to go
+    for cave in 1..names.len() {
+        let candidates = (0..cave).filter(|&other| allowed(&passages, cave, other)).collect::<Vec<_>>();
+        let other = *rng.pick(&candidates);
+        passages.push((other, cave));
+    }
+
+    for _ in 0..caves / 2 {
+        let (a, b) = (rng.index(names.len()), rng.index(names.len()));
+        if allowed(&passages, a, b) {
+            passages.push((a, b));
+        }
+    }
+
+    let lines = passages.iter().map(|&(a, b)| format!("{}-{}", names[a], names[b])).collect::<Vec<_>>();
+    lines.join("\n")
+}
+
+impl Generate for Day12 {
+    // the number of paths grows very quickly with the number of caves, so keep `size` small
+    fn generate(size: usize, seed: u64) -> String {
+        cave_system(size, seed)
+    }
+}
+
+#[test]
+pub fn test_generate() {
+    use crate::{parse_input, part_1, part_2};
+
+    for seed in 0..10 {
+        let input = cave_system(8, seed);
+        assert!(input.lines().all(|l| {
+            let (a, b) = l.split_once('-').unwrap();
+            !(is_big(a) && is_big(b))
+        }));
+
+        let network = parse_input(&input).unwrap();
+        assert!(part_1(&network) >= 1);
+        assert!(part_2(&network) >= part_1(&network));
+    }
+}
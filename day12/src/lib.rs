@@ -0,0 +1,112 @@
+use common::{parse, ParseError, Solution};
+use graph::{Graph, NodeId};
+
+pub mod generate;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CaveType {
+    Small,
+    Large,
+}
+
+#[derive(Debug, Clone)]
+pub struct CaveNetwork {
+    caves: Graph<String>,
+    // indexed by node id
+    types: Vec<CaveType>,
+    start: NodeId,
+    end: NodeId,
+}
+
+impl CaveNetwork {
+    pub fn name(&self, cave: NodeId) -> &str {
+        self.caves.node(cave)
+    }
+
+    fn is_small(&self, cave: NodeId) -> bool {
+        self.types[cave.0] == CaveType::Small
+    }
+
+    // every path from start to end that doesn't go through any small cave twice, except
+    // that with `revisit` one of them (but not start) can be gone through twice
+    pub fn paths(&self, revisit: bool) -> Vec<Vec<NodeId>> {
+        self.caves.paths(self.start, self.end, |path, next| {
+            if !self.is_small(next) || !path.contains(&next) {
+                return true;
+            }
+
+            // already been to this small cave, so it had better be the one we go back to
+            let revisited = path.iter().enumerate()
+                .any(|(i, cave)| self.is_small(*cave) && path[..i].contains(cave));
+            revisit && next != self.start && !revisited
+        })
+    }
+}
+
+pub fn part_1(network: &CaveNetwork) -> usize {
+    network.paths(false).len()
+}
+
+pub fn part_2(network: &CaveNetwork) -> usize {
+    network.paths(true).len()
+}
+
+fn parse_cave<'a>(input: &str, cave: &'a str) -> Result<(&'a str, CaveType), ParseError> {
+    if !cave.is_empty() && cave.chars().all(|c| c.is_ascii_lowercase()) {
+        Ok((cave, CaveType::Small))
+    } else if !cave.is_empty() && cave.chars().all(|c| c.is_ascii_uppercase()) {
+        Ok((cave, CaveType::Large))
+    } else {
+        Err(ParseError::at(input, cave, "a cave name, all in lower case or all in upper case"))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<CaveNetwork, ParseError> {
+    let mut caves = Graph::new();
+    let mut types = Vec::new();
+    // there may not be any way from start to end, but they're always there
+    let start = caves.add_node("start".to_owned());
+    let end = caves.add_node("end".to_owned());
+    types.extend([CaveType::Small, CaveType::Small]);
+    for line in parse::lines(input) {
+        let (left, right) = parse::split_once(input, line, "-", "a passage like `start-A`")?;
+        let (left, left_type) = parse_cave(input, left)?;
+        let (right, right_type) = parse_cave(input, right)?;
+        // we'd be able to go back and forth between them forever
+        if left_type == CaveType::Large && right_type == CaveType::Large {
+            return Err(ParseError::at(input, line, "a passage that doesn't join two big caves"));
+        }
+
+        let (left, right) = (caves.add_node(left.to_owned()), caves.add_node(right.to_owned()));
+        caves.connect(left, right, ());
+        types.resize(caves.len(), CaveType::Small);
+        types[left.0] = left_type;
+        types[right.0] = right_type;
+    }
+
+    Ok(CaveNetwork { caves, types, start, end })
+}
+
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = CaveNetwork;
+    type Output1 = usize;
+    type Output2 = usize;
+    type Error = ParseError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(part_2(input))
+    }
+}
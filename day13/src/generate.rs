@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use common::{Generate, Rng};
+
+use crate::Day13;
+
+// the code that comes out at the end is drawn on a 40x6 sheet, same as the real thing
+const WIDTH: i64 = 40;
+const HEIGHT: i64 = 6;
+
+// a sheet with `dots` dots on it, which folds up five times right to left and seven times
+// bottom to top (in a random order) into a picture with about an eighth as many. returns the
+// puzzle along with the picture folding it all up should give back
+pub fn paper(dots: usize, seed: u64) -> (String, HashSet<(i64, i64)>) {
+    let mut rng = Rng::new(seed);
+    let mut picture = HashSet::new();
+    while picture.len() < (dots / 8).clamp(1, (WIDTH * HEIGHT) as usize) {
+        picture.insert((rng.range(0..WIDTH), rng.range(0..HEIGHT)));
+    }
+
+    // work out where each fold is from the last one backwards: folding at n leaves a sheet
+    // n wide, so the sheet before it must have been 2n + 1
+    let mut axes = [vec!['x'; 5], vec!['y'; 7]].concat();
+    rng.shuffle(&mut axes);
+    let (mut width, mut height) = (WIDTH, HEIGHT);
+    let mut folds = Vec::new();
+    for &axis in axes.iter().rev() {
+        let location = if axis == 'x' { &mut width } else { &mut height };
+        folds.push((axis, *location));
+        *location = 2 * *location + 1;
+    }
+    folds.reverse();
+
+    // then unfold dots of the picture, flipping them across each fold line or not at random.
+    // every dot gets at least one go, and nothing ever lands on a fold line
+    let mut picture_dots = picture.iter().copied().collect::<Vec<_>>();
+    picture_dots.sort_unstable();
+    let mut sheet = HashSet::new();
+    let mut next = 0;
+    while sheet.len() < dots.max(picture_dots.len()) {
+        let (mut x, mut y) = if next < picture_dots.len() { picture_dots[next] } else { *rng.pick(&picture_dots) };
+        next += 1;
+        for &(axis, location) in folds.iter().rev() {
+            if rng.chance(0.5) {
+                match axis {
+                    'x' => x = 2 * location - x,
+                    _ => y = 2 * location - y,
+                }
+            }
+        }
+        sheet.insert((x, y));
+    }
+
+    let mut sheet = sheet.into_iter().collect::<Vec<_>>();
+    sheet.sort_unstable();
+    rng.shuffle(&mut sheet);
+
+    let dots = sheet.iter().map(|(x, y)| format!("{},{}", x, y)).collect::<Vec<_>>();
+    let folds = folds.iter().map(|(axis, location)| format!("fold along {}={}", axis, location)).collect::<Vec<_>>();
+    (format!("{}\n\n{}", dots.join("\n"), folds.join("\n")), picture)
+}
+
+impl Generate for Day13 {
+    fn generate(size: usize, seed: u64) -> String {
+        paper(size, seed).0
+    }
+}
+
+#[test]
+pub fn test_generate() {
+    use crate::{parse_input, part_2};
+
+    for seed in 0..10 {
+        let (input, picture) = paper(100, seed);
+        let folded = part_2(&parse_input(&input).unwrap());
+        let dots = folded.dots.to_sparse(|&dot| dot).into_keys().map(|p| (p.x as i64, p.y as i64)).collect::<HashSet<_>>();
+        assert_eq!(dots, picture);
+    }
+}
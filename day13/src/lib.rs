@@ -0,0 +1,180 @@
+use common::{parse, trace, ParseError, Solution};
+use grid::image::{Image, Render};
+use grid::{Grid, Point};
+
+pub mod generate;
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FoldType { Horizontal, Vertical }
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Fold {
+    fold_type: FoldType,
+    location: usize,
+}
+
+impl std::fmt::Display for Fold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.fold_type {
+            FoldType::Horizontal => write!(f, "fold along y={}", self.location),
+            FoldType::Vertical => write!(f, "fold along x={}", self.location),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    dots: Grid<bool>,
+    instructions: Vec<Fold>
+}
+
+impl std::fmt::Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.dots.render(|&dot| if dot { '#' } else { '.' }))
+    }
+}
+
+impl Input {
+    // everything past the fold lands on its mirror image, so the paper ends up exactly as
+    // big as the part before the fold
+    pub fn follow_fold(&self, fold: Fold) -> Input {
+        let l = fold.location;
+        // the paper's only as big as the furthest dot, so a fold can be past the edge
+        let dot = |x, y| self.dots.get(Point { x, y }) == Some(&true);
+        let mirror = |n: usize| l.checked_mul(2).and_then(|twice| twice.checked_sub(n));
+        let new_dots = match fold.fold_type {
+            FoldType::Horizontal => Grid::from_fn(self.dots.width(), l, |Point { x, y }| {
+                dot(x, y) || mirror(y).is_some_and(|y| dot(x, y))
+            }),
+            FoldType::Vertical => Grid::from_fn(l, self.dots.height(), |Point { x, y }| {
+                dot(x, y) || mirror(x).is_some_and(|x| dot(x, y))
+            }),
+        };
+
+        let folded = Input { dots: new_dots, ..self.clone() };
+        trace::snapshot(13, format_args!("{} ({} dots)", fold, folded.count_dots()), &folded);
+        folded
+    }
+
+    pub fn fold(&self) -> Input {
+        let mut data = self.clone();
+        for instruction in &self.instructions {
+            data = data.follow_fold(*instruction);
+        }
+
+        data
+    }
+
+    pub fn count_dots(&self) -> usize {
+        self.dots.values().filter(|&&dot| dot).count()
+    }
+}
+
+pub fn part_1(input: &Input) -> usize {
+    input.follow_fold(input.instructions[0]).count_dots()
+}
+
+pub fn part_2(input: &Input) -> Input {
+    input.fold()
+}
+
+// the paper's kept as a whole grid of dots, so one a long way out (or a fold there) would
+// need a sheet far too big to hold. real ones are about 1300 by 900
+const MAX_COORDINATE: usize = 4096;
+
+fn coordinate(input: &str, n: &str) -> Result<usize, ParseError> {
+    let coordinate = parse::number(input, n)?;
+    if coordinate >= MAX_COORDINATE {
+        return Err(ParseError::at(input, n, format!("a number less than {}", MAX_COORDINATE)));
+    }
+
+    Ok(coordinate)
+}
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut dots = Vec::new();
+    let mut instructions = Vec::new();
+    let (dot_portion, instruction_portion) = match parse::sections(input)[..] {
+        [dots, folds] => (dots, folds),
+        [_] | [] => return Err(ParseError::at_end(input, "a blank line and then the folds")),
+        [_, _, extra, ..] => return Err(ParseError::at(input, extra, "the end of the folds")),
+    };
+    for dot in parse::lines(dot_portion) {
+        let [x, y] = parse::array(input, dot, ",", "a dot like `6,10`", |n| coordinate(input, n))?;
+        dots.push((Point { x, y }, true));
+    }
+
+    for instruction in parse::lines(instruction_portion) {
+        let fold = parse::strip_prefix(input, instruction, "fold along ")?;
+        let (direction, location) = parse::split_once(input, fold, "=", "a fold like `y=7`")?;
+        let fold_type = match direction {
+            "y" => FoldType::Horizontal,
+            "x" => FoldType::Vertical,
+            _ => return Err(ParseError::at(input, direction, "`x` or `y`")),
+        };
+        instructions.push(Fold { fold_type, location: coordinate(input, location)? });
+    }
+
+    if instructions.is_empty() {
+        return Err(ParseError::at_end(input, "at least one fold"));
+    }
+
+    Ok(Input { dots: Grid::from_sparse(dots, false), instructions })
+}
+
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = Input;
+    type Error = ParseError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(part_2(input))
+    }
+}
+
+// the letters on the folded paper
+impl Render for Day13 {
+    fn render(input: &Self::Input) -> Result<Image, Self::Error> {
+        Ok(Image::from_bools(&part_2(input).dots))
+    }
+}
+
+#[test]
+pub fn test() {
+    let input = include_str!("../fixtures/example.txt");
+
+    let data = parse_input(input).unwrap();
+    let (folded, steps) = trace::record(|| data.fold());
+    assert_eq!(steps[0].step, "fold along y=7 (17 dots)");
+    assert_eq!(steps[1].step, "fold along x=5 (16 dots)");
+    assert_eq!(steps[1].state, folded.to_string());
+    assert_eq!(data.follow_fold(data.instructions[0]).count_dots(), 17);
+}
+
+#[test]
+pub fn test_too_far() {
+    let error = parse_input("6,10\n1000000000,5\n\nfold along y=7\n").unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 1, "1000000000"));
+    assert_eq!(error.expected, "a number less than 4096");
+    let error = parse_input("6,10\n\nfold along x=18446744073709551615\n").unwrap_err();
+    assert_eq!((error.line, error.column), (3, 14));
+
+    // folds well past the edge of the paper just make it bigger
+    let input = parse_input("4095,0\n2,1\n\nfold along x=4000\nfold along y=100\n").unwrap();
+    assert_eq!(part_1(&input), 2);
+    assert_eq!(part_2(&input).count_dots(), 2);
+}
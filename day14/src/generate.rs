@@ -0,0 +1,25 @@
+use common::{Generate, Rng};
+
+use crate::Day14;
+
+const ELEMENTS: &str = "BCFHKNOPSV";
+
+impl Generate for Day14 {
+    // a polymer template `size` elements long, and an insertion rule for every pair of the
+    // ten elements so no pair ever goes without one
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let elements = ELEMENTS.chars().collect::<Vec<_>>();
+        let template = (0..size.max(1)).map(|_| *rng.pick(&elements)).collect::<String>();
+
+        let mut rules = Vec::new();
+        for &a in &elements {
+            for &b in &elements {
+                rules.push(format!("{}{} -> {}", a, b, rng.pick(&elements)));
+            }
+        }
+        rng.shuffle(&mut rules);
+
+        format!("{}\n\n{}", template, rules.join("\n"))
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+use std::fmt;
+use itertools::Itertools;
+use thiserror::Error;
+use common::{parse, trace, Count, Overflow, ParseError, Solution};
+
+pub mod generate;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Instruction {
+    input: [char; 2],
+    new_char: char,
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    start: String,
+    rules: HashMap<[char; 2], char>,
+}
+
+impl Input {
+    pub fn to_state(&self) -> State {
+        let mut current_pairs_counts = HashMap::new();
+        let mut char_counts = HashMap::new();
+
+        for (first, second) in self.start.chars().tuple_windows() {
+            *current_pairs_counts.entry([first, second]).or_insert(0) += 1;
+        }
+
+        for c in self.start.chars() {
+            *char_counts.entry(c).or_insert(0) += 1;
+        }
+
+        let current_pairs_counts = current_pairs_counts.into_iter().map(|(pair, n)| (pair, Count::from(n as u64))).collect();
+        let char_counts = char_counts.into_iter().map(|(c, n)| (c, Count::from(n as u64))).collect();
+
+        State { current_pairs_counts, char_counts }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    current_pairs_counts: HashMap<[char; 2], Count>,
+    char_counts: HashMap<char, Count>,
+}
+
+fn add<K: std::hash::Hash + Eq>(counts: &mut HashMap<K, Count>, key: K, count: &Count) -> Result<(), Overflow> {
+    let total = counts.entry(key).or_default();
+    *total = total.checked_add(count)?;
+    Ok(())
+}
+
+impl State {
+    // the polymer roughly doubles in length every step, so the counts overflow a u64 after
+    // about 60 of them
+    pub fn apply_rules(&self, rules: &HashMap<[char; 2], char>) -> Result<State, Overflow> {
+        let mut new_pairs_counts = HashMap::new();
+        let mut new_char_counts = self.char_counts.clone();
+
+        for (pair, count) in &self.current_pairs_counts {
+            match rules.get(pair) {
+                Some(c) => {
+                    // all of this pair become two new pairs, and we get the new character
+                    add(&mut new_pairs_counts, [pair[0], *c], count)?;
+                    add(&mut new_pairs_counts, [*c, pair[1]], count)?;
+                    add(&mut new_char_counts, *c, count)?;
+                }
+                None => {
+                    // no rule for this pair, it just goes through
+                    add(&mut new_pairs_counts, *pair, count)?;
+                }
+            }
+        }
+
+        Ok(State { current_pairs_counts: new_pairs_counts, char_counts: new_char_counts })
+    }
+}
+
+// the polymer itself gets far too long to show, so just count up the elements
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = self.char_counts.iter().sorted().map(|(c, count)| format!("{}: {}", c, count)).join(", ");
+        match Count::sum(self.char_counts.values()) {
+            Ok(length) => write!(f, "length {} ({})", length, counts),
+            Err(_) => write!(f, "length uncountable ({})", counts),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let (start, replacements) = match parse::sections(input)[..] {
+        [start, rules] => (start, rules),
+        [_] | [] => return Err(ParseError::at_end(input, "a blank line and then the insertion rules")),
+        [_, _, extra, ..] => return Err(ParseError::at(input, extra, "the end of the insertion rules")),
+    };
+    if start.contains('\n') {
+        return Err(ParseError::at(input, start, "a polymer template on a single line"));
+    }
+
+    let mut rules = HashMap::new();
+    for replacement in parse::lines(replacements) {
+        let (pair, new) = parse::split_once(input, replacement, " -> ", "a rule like `CH -> B`")?;
+        let lhs = match pair.chars().collect::<Vec<_>>()[..] {
+            [a, b] => [a, b],
+            _ => return Err(ParseError::at(input, pair, "a pair of elements")),
+        };
+        let rhs = match new.chars().collect::<Vec<_>>()[..] {
+            [c] => c,
+            _ => return Err(ParseError::at(input, new, "a single element")),
+        };
+        if rules.insert(lhs, rhs).is_some() {
+            return Err(ParseError::at(input, replacement, "only one rule for each pair"));
+        }
+    }
+
+    Ok(Input { start: start.to_string(), rules })
+}
+
+pub fn solve(input: &Input, steps: usize) -> Result<Count, Overflow> {
+    let mut state = input.to_state();
+    for step in 1 ..= steps {
+        state = state.apply_rules(&input.rules)?;
+        trace::snapshot(14, format_args!("step {}", step), &state);
+    }
+
+    let max_count = state.char_counts.values().max().unwrap();
+    let min_count = state.char_counts.values().min().unwrap();
+    max_count.checked_sub(min_count)
+}
+
+
+#[derive(Error, Debug)]
+pub enum SolutionError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Input;
+    type Output1 = Count;
+    type Output2 = Count;
+    type Error = SolutionError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(solve(input, 10)?)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(solve(input, 40)?)
+    }
+}
+
+#[test]
+pub fn test() {
+    let input = include_str!("../fixtures/example.txt");
+
+    let input = parse_input(input).unwrap();
+    let (answer, steps) = trace::record(|| solve(&input, 10));
+    assert_eq!(answer, Ok(Count::from(1588u64)));
+    assert_eq!(steps[1].state, "length 13 (B: 6, C: 4, H: 1, N: 2)");
+    assert_eq!(steps[9].state, "length 3073 (B: 1749, C: 298, H: 161, N: 865)");
+
+    if common::count::UNBOUNDED {
+        assert!(solve(&input, 80).unwrap() > Count::from(u64::MAX));
+    } else {
+        assert_eq!(solve(&input, 80), Err(Overflow));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+use common::{Generate, Rng};
+
+use crate::Day15;
+
+impl Generate for Day15 {
+    // a square cavern `size` on each side, with risks from 1 to 9
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let rows = (0..size).map(|_| {
+            (0..size).map(|_| rng.range(1..10).to_string()).collect::<String>()
+        }).collect::<Vec<_>>();
+
+        rows.join("\n")
+    }
+}
@@ -0,0 +1,122 @@
+use common::{ParseError, Solution};
+use graph::Graph;
+use grid::image::{Image, Palette, Render};
+use grid::{Grid, Point};
+
+pub mod generate;
+
+#[derive(Copy, Clone, Debug)]
+pub struct Risk(u32);
+
+#[derive(Clone, Debug)]
+pub struct Cavern {
+    cells: Grid<Risk>,
+}
+
+impl Cavern {
+    pub fn end(&self) -> Point {
+        (self.cells.width() - 1, self.cells.height() - 1).into()
+    }
+
+    pub fn to_expanded(&self) -> Cavern {
+        let (width, height) = (self.cells.width(), self.cells.height());
+        let new_cells = Grid::from_fn(5 * width, 5 * height, |Point { x, y }| {
+            let Risk(risk) = self.cells[(x % width, y % height).into()];
+            let mut new_risk = risk + (x / width + y / height) as u32;
+            if new_risk > 9 {
+                new_risk -= 9;
+            }
+            Risk(new_risk)
+        });
+
+        Cavern { cells: new_cells }
+    }
+
+    // each point leads to its neighbours, at the risk of the neighbour
+    pub fn graph(&self) -> Graph<Point, u32> {
+        let mut graph = Graph::with_capacity(self.cells.len());
+        let ids = Grid::from_fn(self.cells.width(), self.cells.height(), |point| graph.add_node(point));
+        for point in self.cells.points() {
+            for neighbour in self.cells.neighbours(point) {
+                graph.add_edge(ids[point], ids[neighbour], self.cells[neighbour].0);
+            }
+        }
+
+        graph
+    }
+
+    pub fn lowest_risks(&self) -> Grid<Option<u32>> {
+        let graph = self.graph();
+        let start = graph.id(&(0, 0).into()).unwrap();
+        let risks = graph.dijkstra(start);
+        Grid::from_fn(self.cells.width(), self.cells.height(), |point| graph.id(&point).and_then(|id| risks.cost(id)))
+    }
+
+    // just the way to the end. every step's at least 1, so it can't be any less risky than
+    // the number of steps left
+    pub fn lowest_risk_to_end(&self) -> u32 {
+        let graph = self.graph();
+        let (start, end) = (graph.id(&(0, 0).into()).unwrap(), graph.id(&self.end()).unwrap());
+        let Point { x: end_x, y: end_y } = self.end();
+        let risks = graph.astar(start, end, |point| (end_x - point.x + end_y - point.y) as u32);
+        risks.cost(end).unwrap()
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Cavern, ParseError> {
+    let cells = Grid::parse(input, "a digit", |c| c.to_digit(10).map(Risk))?;
+    if cells.is_empty() {
+        return Err(ParseError::at_end(input, "at least one row of risk levels"));
+    }
+
+    Ok(Cavern { cells })
+}
+
+pub fn part_1(cavern: &Cavern) -> u32 {
+    cavern.lowest_risk_to_end()
+}
+
+pub fn part_2(cavern: &Cavern) -> u32 {
+    let bigger_cavern = cavern.to_expanded();
+    part_1(&bigger_cavern)
+}
+
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Cavern;
+    type Output1 = u32;
+    type Output2 = u32;
+    type Error = ParseError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(part_2(input))
+    }
+}
+
+// the lowest total risk of getting to each point of the full cavern from the top left
+impl Render for Day15 {
+    fn render(input: &Self::Input) -> Result<Image, Self::Error> {
+        let risks = input.to_expanded().lowest_risks().map(|risk| risk.unwrap_or(0));
+        Ok(Image::from_values(&risks, Palette::Viridis))
+    }
+}
+
+#[test]
+pub fn test_empty() {
+    for input in ["", "\n", " \n\n"] {
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(error.expected, "at least one row of risk levels");
+    }
+}
@@ -0,0 +1,105 @@
+use common::{Generate, Rng};
+
+use crate::{Bit, Day16, Packet, Payload};
+
+// a random packet tree with about `size` packets in it. products only ever get literals
+// underneath them, and literals stay below 4096, so evaluating it can't overflow
+pub fn packet(size: usize, seed: u64) -> Packet {
+    let mut rng = Rng::new(seed);
+    let mut budget = size.max(1);
+    random_packet(&mut rng, &mut budget, 0)
+}
+
+fn literal(rng: &mut Rng, budget: &mut usize) -> Packet {
+    *budget = budget.saturating_sub(1);
+    Packet { version: rng.below(8), payload: Payload::Literal(rng.below(4096)) }
+}
+
+fn random_packet(rng: &mut Rng, budget: &mut usize, depth: usize) -> Packet {
+    if *budget <= 1 || depth >= 8 || (depth > 0 && rng.chance(0.3)) {
+        return literal(rng, budget);
+    }
+
+    *budget -= 1;
+    let type_id = *rng.pick(&[0, 1, 2, 3, 5, 6, 7]);
+    let packets = match type_id {
+        1 => (0..rng.range(1..4)).map(|_| literal(rng, budget)).collect(),
+        5..=7 => (0..2).map(|_| random_packet(rng, budget, depth + 1)).collect(),
+        _ => (0..rng.range(1..5)).map(|_| random_packet(rng, budget, depth + 1)).collect(),
+    };
+
+    Packet { version: rng.below(8), payload: Payload::Operation { type_id, packets } }
+}
+
+fn push_number(bits: &mut Vec<Bit>, value: u64, width: usize) {
+    for i in (0..width).rev() {
+        bits.push(if (value >> i) & 1 == 1 { Bit::One } else { Bit::Zero });
+    }
+}
+
+fn encode_packet(rng: &mut Rng, packet: &Packet, bits: &mut Vec<Bit>) {
+    push_number(bits, packet.version, 3);
+    match &packet.payload {
+        Payload::Literal(value) => {
+            push_number(bits, 4, 3);
+            let groups = (u64::BITS - value.leading_zeros()).div_ceil(4).max(1);
+            for group in (0..groups).rev() {
+                bits.push(if group > 0 { Bit::One } else { Bit::Zero });
+                push_number(bits, (value >> (4 * group)) & 0xf, 4);
+            }
+        }
+        Payload::Operation { type_id, packets } => {
+            push_number(bits, *type_id, 3);
+            let mut contents = Vec::new();
+            for packet in packets {
+                encode_packet(rng, packet, &mut contents);
+            }
+
+            // either length type will do, as long as the length fits in it
+            if contents.len() < 1 << 15 && rng.chance(0.5) {
+                bits.push(Bit::Zero);
+                push_number(bits, contents.len() as u64, 15);
+            } else {
+                bits.push(Bit::One);
+                push_number(bits, packets.len() as u64, 11);
+            }
+            bits.extend(contents);
+        }
+    }
+}
+
+// the hex transmission for `packet`, with a random choice of length type for each operator
+// and zeros on the end to fill out the last digit, like the real thing
+pub fn encode(packet: &Packet, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut bits = Vec::new();
+    encode_packet(&mut rng, packet, &mut bits);
+    while bits.len() % 4 != 0 {
+        bits.push(Bit::Zero);
+    }
+
+    bits.chunks(4)
+        .map(|nibble| {
+            let value = nibble.iter().fold(0, |n, bit| (n << 1) | (*bit == Bit::One) as u32);
+            char::from_digit(value, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect()
+}
+
+impl Generate for Day16 {
+    fn generate(size: usize, seed: u64) -> String {
+        encode(&packet(size, seed), seed)
+    }
+}
+
+#[test]
+pub fn test_generate() {
+    use crate::parse_input;
+
+    for seed in 0..20 {
+        let packet = packet(100, seed);
+        let parsed = parse_input(&encode(&packet, seed)).unwrap();
+        assert_eq!(parsed, packet);
+        assert_eq!(parsed.evaluate(), packet.evaluate());
+    }
+}
@@ -0,0 +1,252 @@
+use thiserror::Error;
+use common::{parse, Count, Overflow, ParseError, Solution};
+
+pub mod generate;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Bit {
+    Zero,
+    One,
+}
+
+pub fn to_bits(c: char) -> Option<[Bit; 4]> {
+    Some(match c {
+        '0' => [Bit::Zero, Bit::Zero, Bit::Zero, Bit::Zero],
+        '1' => [Bit::Zero, Bit::Zero, Bit::Zero, Bit::One],
+        '2' => [Bit::Zero, Bit::Zero, Bit::One, Bit::Zero],
+        '3' => [Bit::Zero, Bit::Zero, Bit::One, Bit::One],
+
+        '4' => [Bit::Zero, Bit::One, Bit::Zero, Bit::Zero],
+        '5' => [Bit::Zero, Bit::One, Bit::Zero, Bit::One],
+        '6' => [Bit::Zero, Bit::One, Bit::One, Bit::Zero],
+        '7' => [Bit::Zero, Bit::One, Bit::One, Bit::One],
+
+        '8' => [Bit::One, Bit::Zero, Bit::Zero, Bit::Zero],
+        '9' => [Bit::One, Bit::Zero, Bit::Zero, Bit::One],
+        'A' => [Bit::One, Bit::Zero, Bit::One, Bit::Zero],
+        'B' => [Bit::One, Bit::Zero, Bit::One, Bit::One],
+
+        'C' => [Bit::One, Bit::One, Bit::Zero, Bit::Zero],
+        'D' => [Bit::One, Bit::One, Bit::Zero, Bit::One],
+        'E' => [Bit::One, Bit::One, Bit::One, Bit::Zero],
+        'F' => [Bit::One, Bit::One, Bit::One, Bit::One],
+
+        _ => return None,
+    })
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Payload {
+    Literal(u64),
+    Operation {
+        type_id: u64,
+        packets: Vec<Packet>
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Packet {
+    version: u64,
+    payload: Payload,
+}
+
+impl Packet {
+    pub fn total_version(&self) -> u64 {
+        self.version + match &self.payload {
+            Payload::Literal(_) => 0,
+            Payload::Operation { type_id: _, packets } => {
+                packets.iter().map(|p| p.total_version()).sum()
+            }
+        }
+    }
+
+    // the literals all fit in a u64, but their sums and products needn't
+    pub fn evaluate(&self) -> Result<Count, Overflow> {
+        match &self.payload {
+            Payload::Literal(val) => Ok(Count::from(*val)),
+            Payload::Operation { type_id, packets } => {
+                let values = packets.iter().map(|p| p.evaluate()).collect::<Result<Vec<_>, _>>()?;
+                let flag = |b: bool| Count::from(b as u64);
+                Ok(match type_id {
+                    0 => Count::sum(&values)?,
+                    1 => Count::product(&values)?,
+                    2 => values.into_iter().min().unwrap(),
+                    3 => values.into_iter().max().unwrap(),
+                    5 => flag(values[0] > values[1]),
+                    6 => flag(values[0] < values[1]),
+                    7 => flag(values[0] == values[1]),
+                    _ => unreachable!(),
+                })
+            }
+        }
+    }
+}
+
+pub fn to_number(bits: &[Bit]) -> u64 {
+    let mut result = 0;
+    for (power, bit) in bits.iter().rev().enumerate() {
+        if bit == &Bit::One {
+            result += 2u64.pow(power as u32);
+        }
+    }
+
+    result
+}
+
+pub fn parse_input(input: &str) -> Result<Packet, ParseError> {
+    let transmission = input.trim();
+    let mut bits = parse::chars(input, transmission, "a hexadecimal digit", to_bits)?.concat();
+
+    parse_packet(&mut bits).ok_or_else(|| ParseError::at(input, transmission, "a complete, well-formed packet"))
+}
+
+// the next `n` bits, if there are that many left
+fn take(input: &mut Vec<Bit>, n: usize) -> Option<Vec<Bit>> {
+    if input.len() < n {
+        return None;
+    }
+
+    Some(input.drain(0..n).collect())
+}
+
+// None if the bits run out part way through, or an operator has the wrong number of
+// sub-packets to make sense
+pub fn parse_packet(input: &mut Vec<Bit>) -> Option<Packet> {
+    let version = to_number(&take(input, 3)?);
+    let type_id = to_number(&take(input, 3)?);
+
+    if type_id == 4 {
+        let number = parse_literal(input)?;
+        return Some(Packet { version, payload: Payload::Literal(number) });
+    }
+
+    let mut parsed_sub_packets = Vec::new();
+    match take(input, 1)?[0] {
+        Bit::Zero => {
+            let length_of_sub_packets = to_number(&take(input, 15)?) as usize;
+
+            let mut sub_packets_to_parse = take(input, length_of_sub_packets)?;
+            while !sub_packets_to_parse.is_empty() {
+                parsed_sub_packets.push(parse_packet(&mut sub_packets_to_parse)?);
+            }
+        },
+
+        Bit::One => {
+            let number_of_sub_packets = to_number(&take(input, 11)?);
+
+            for _ in 1..=number_of_sub_packets {
+                parsed_sub_packets.push(parse_packet(input)?);
+            }
+        }
+    }
+
+    // comparisons need exactly two things to compare, and everything else at least one
+    match (type_id, parsed_sub_packets.len()) {
+        (5..=7, 2) => (),
+        (5..=7, _) | (_, 0) => return None,
+        _ => (),
+    }
+
+    Some(Packet { version, payload: Payload::Operation { type_id, packets: parsed_sub_packets } })
+}
+
+// a literal too big for a u64 is as good as broken for us
+pub fn parse_literal(input: &mut Vec<Bit>) -> Option<u64> {
+    let mut bits = Vec::new();
+    loop {
+        let next_chunk = take(input, 5)?;
+        bits.extend_from_slice(&next_chunk[1..=4]);
+        if next_chunk[0] == Bit::Zero {
+            break;
+        }
+    }
+
+    if bits.len() > 64 && bits[..bits.len() - 64].contains(&Bit::One) {
+        return None;
+    }
+
+    Some(to_number(&bits[bits.len().saturating_sub(64)..]))
+}
+
+
+#[derive(Error, Debug)]
+pub enum SolutionError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Packet;
+    type Output1 = u64;
+    type Output2 = Count;
+    type Error = SolutionError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(input.total_version())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(input.evaluate()?)
+    }
+}
+
+#[test]
+pub fn test_to_number() {
+    assert_eq!(to_number(&[Bit::One, Bit::Zero, Bit::Zero]), 4);
+    assert_eq!(to_number(&[Bit::One, Bit::Zero, Bit::One]), 5);
+    assert_eq!(to_number(&[Bit::Zero, Bit::One, Bit::One]), 3);
+}
+
+#[test]
+pub fn test_parse_packet() {
+    assert_eq!(parse_input("D2FE28").unwrap(), Packet { version: 6, payload: Payload::Literal(2021)});
+    assert_eq!(parse_input("38006F45291200").unwrap(),
+        Packet { version: 1, payload: Payload::Operation {
+            type_id: 6, packets: vec![
+                Packet { version: 6, payload: Payload::Literal(10)},
+                Packet { version: 2, payload: Payload::Literal(20)}
+            ]
+        }});
+    assert_eq!(parse_input("EE00D40C823060").unwrap(),
+        Packet { version: 7, payload: Payload::Operation {
+            type_id: 3, packets: vec![
+                Packet { version: 2, payload: Payload::Literal(1)},
+                Packet { version: 4, payload: Payload::Literal(2)},
+                Packet { version: 1, payload: Payload::Literal(3)}
+            ]
+        }});
+}
+
+#[test]
+pub fn test_overflow() {
+    let huge = Packet { version: 0, payload: Payload::Operation {
+        type_id: 1, packets: vec![
+            Packet { version: 0, payload: Payload::Literal(u64::MAX) },
+            Packet { version: 0, payload: Payload::Literal(2) },
+        ]
+    }};
+    if common::count::UNBOUNDED {
+        assert_eq!(huge.evaluate().unwrap().to_string(), "36893488147419103230");
+    } else {
+        assert_eq!(huge.evaluate(), Err(Overflow));
+    }
+}
+#[test]
+pub fn test_parse_error() {
+    let error = parse_input("D2FG28").unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (1, 4, "G"));
+
+    // cut off part way through the literal
+    let error = parse_input("D2F").unwrap_err();
+    assert_eq!(error.expected, "a complete, well-formed packet");
+}
@@ -0,0 +1,56 @@
+use common::{Generate, Rng};
+
+use crate::Day17;
+
+impl Generate for Day17 {
+    // a target area up to about twice `size` across, somewhere to the right of and below the
+    // probe like the real ones. some horizontal speed always runs out right above it, which
+    // is what lets the probe go as high as part 1 takes for granted
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let size = size.max(2) as i64;
+        let mut v_x = 1;
+        while v_x * (v_x + 1) / 2 < size {
+            v_x += 1;
+        }
+        v_x += rng.range(0..3);
+        let stop = v_x * (v_x + 1) / 2;
+        let x_min = (stop - rng.range(0..size)).max(1);
+        let x_max = stop + rng.range(0..size);
+        let y_min = -2 * size - rng.range(0..size);
+        let y_max = y_min + rng.range(1..size);
+
+        format!("target area: x={}..{}, y={}..{}", x_min, x_max, y_min, y_max)
+    }
+}
+
+#[test]
+pub fn test_generate() {
+    use crate::{parse_input, part_1, part_2};
+
+    // fire the probe at every velocity that could possibly hit and see what actually does
+    for seed in 0..5 {
+        let target = parse_input(&Day17::generate(30, seed)).unwrap();
+        let mut hits = Vec::new();
+        for x_v in 0..=*target.x.end() {
+            for y_v in *target.y.start()..=-*target.y.start() {
+                let (mut x, mut y, mut v_x, mut v_y) = (0, 0, x_v, y_v);
+                let mut highest = 0;
+                while x <= *target.x.end() && y >= *target.y.start() {
+                    if target.x.contains(&x) && target.y.contains(&y) {
+                        hits.push(highest);
+                        break;
+                    }
+                    x += v_x;
+                    y += v_y;
+                    highest = highest.max(y);
+                    v_x -= v_x.signum();
+                    v_y -= 1;
+                }
+            }
+        }
+
+        assert_eq!(part_2(&target), hits.len());
+        assert_eq!(part_1(&target), *hits.iter().max().unwrap());
+    }
+}
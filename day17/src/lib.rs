@@ -0,0 +1,143 @@
+use std::ops::RangeInclusive;
+use std::collections::HashSet;
+use common::{parse, ParseError, Solution};
+
+pub mod generate;
+
+#[derive(Clone, Debug)]
+pub struct TargetArea {
+    x: RangeInclusive<i64>,
+    y: RangeInclusive<i64>
+}
+
+impl TargetArea {
+    pub fn viable_x_velocities(&self) -> Vec<i64> {
+        // the x velocity (absolutely) decreases by one each step.
+        // therefore, the x coordinate traces out 'inverse triangular numbers'.
+        // for now assume that the target area has positive x and fully negative y - the input
+        // and the test both satisfy this.
+
+        // every x value in the target area is a viable starting velocity - we could always pick
+        // a y value within the target area too.
+        // otherwise a starting velocity is only good if one of the partials
+        // v, v + v - 1, v + v - 1 + v - 2. ...
+        // intersects the target area x region.
+        let mut x_velocities = Vec::new();
+
+        for starting_v in 0..=*self.x.end() {
+            if (0..=starting_v).rev()
+                .scan(0, |sum, i| { *sum += i; Some(*sum)})
+                .any(|p| self.x.contains(&p)) {
+                    x_velocities.push(starting_v);
+                }
+        }
+
+        x_velocities
+    }
+
+    pub fn viable_y_velocities(&self) -> Vec<i64> {
+        // the y velocity decreases by one each step until it hits 0 and then keeps going
+        // so the y value will always hit 0 again at the other side of the parabola (aha!)
+        // so the absolute biggest y value we could try would be the low y value + 1
+        let mut y_velocities = Vec::new();
+
+        // so there are two possibilities here.
+        // we are checking if, starting with a move downwards of value starting_v, 
+        // we eventually hit the target area.
+        // a move downwards with value v from the horizon comes in one of two ways:
+        // either we started with a y velocity of -v, and this is our first move
+        // or we started with a y velocity of +(v-1), and this move is happening after
+        // we come back down to the horizon again
+        for starting_v in 0..=(-*self.y.start()) {
+            if (starting_v..=(-*self.y.start()))
+                .scan(0, |sum, i| { *sum += i; Some(*sum)})
+                .any(|p| self.y.contains(&(-p))) {
+                    y_velocities.push(-starting_v); // make sure to add in both possibiltiies here
+                    if starting_v > 0 {
+                        y_velocities.push(starting_v-1);
+                    }
+                }
+        }
+
+        y_velocities
+    }
+}
+
+pub fn working_initial_velocities(target: &TargetArea) -> HashSet<(i64, i64)> {
+    let mut result = HashSet::new();
+    let x_velocities = target.viable_x_velocities();
+    let y_velocities = target.viable_y_velocities();
+
+    for y_v in &y_velocities {
+        // check this velocity for all xs - can we hit the target?
+        for x_v in &x_velocities {
+            let x_v_steps = (0..=*x_v).rev().chain(std::iter::repeat(0));
+            let y_v_steps = (i64::MIN..=*y_v).rev();
+            let positions = x_v_steps.zip(y_v_steps)
+                .scan((0, 0), |(pos_x, pos_y), (v_x, v_y)| { *pos_x += v_x; *pos_y += v_y; Some((*pos_x, *pos_y))})
+                .take_while(|(x, y)| x <= target.x.end() && y >= target.y.start())
+                .collect::<Vec<_>>();
+            if positions.iter().any(|p| target.x.contains(&p.0) && target.y.contains(&p.1)) {
+                result.insert((*x_v, *y_v));
+            }
+        }
+    }
+
+    result
+}
+
+pub fn part_1(target: &TargetArea) -> i64 {
+    let best_y = *working_initial_velocities(target).iter().map(|(_, y)| y).max().unwrap();
+    best_y * (best_y + 1) / 2
+}
+
+pub fn part_2(target: &TargetArea) -> usize {
+    working_initial_velocities(target).len()
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ShootResult {
+    Inconclusive,
+    Hit(i64, i64),
+    CannotHit,
+}
+
+fn parse_range(input: &str, range: &str) -> Result<RangeInclusive<i64>, ParseError> {
+    let (min, max) = parse::split_once(input, range, "..", "a range like `20..30`")?;
+    let (min, max) = (parse::number(input, min)?, parse::number(input, max)?);
+    if min > max {
+        return Err(ParseError::at(input, range, "a range with the smaller end first"));
+    }
+
+    Ok(min..=max)
+}
+
+pub fn parse_input(input: &str) -> Result<TargetArea, ParseError> {
+    let shorter = parse::strip_prefix(input, input.trim(), "target area: x=")?;
+    let (x_range, y_range) = parse::split_once(input, shorter, ", y=", "`x=..., y=...`")?;
+    Ok(TargetArea { x: parse_range(input, x_range)?, y: parse_range(input, y_range)? })
+}
+
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = TargetArea;
+    type Output1 = i64;
+    type Output2 = usize;
+    type Error = ParseError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(part_2(input))
+    }
+}
@@ -0,0 +1,35 @@
+use common::{Generate, Rng};
+
+use crate::Day18;
+
+// a reduced snailfish pair `depth` pairs deep: no pair inside four others, no number over 9
+fn pair(rng: &mut Rng, depth: usize) -> String {
+    let element = |rng: &mut Rng| {
+        if depth < 4 && rng.chance(0.6) {
+            pair(rng, depth + 1)
+        } else {
+            rng.range(0..10).to_string()
+        }
+    };
+
+    let left = element(rng);
+    let right = element(rng);
+    format!("[{},{}]", left, right)
+}
+
+impl Generate for Day18 {
+    // `size` snailfish numbers, all already reduced
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        (0..size).map(|_| pair(&mut rng, 1)).collect::<Vec<_>>().join("\n")
+    }
+}
+
+#[test]
+pub fn test_generate() {
+    use crate::parse_input;
+
+    for number in parse_input(&Day18::generate(50, 7)).unwrap() {
+        assert_eq!(number.simplify(), number);
+    }
+}
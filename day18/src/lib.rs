@@ -0,0 +1,396 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+use itertools::Itertools;
+use thiserror::Error;
+use common::{parse, trace, Count, Overflow, ParseError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub mod generate;
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum SnailfishDigit {
+    OpenBrace,
+    CloseBrace,
+    Number(u64),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnailfishNumber {
+    digits: Vec<SnailfishDigit>,
+}
+
+impl fmt::Display for SnailfishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut previous = None;
+        for digit in &self.digits {
+            // a comma between two elements, whichever kind they are
+            let ends_element = matches!(previous, Some(SnailfishDigit::Number(_) | SnailfishDigit::CloseBrace));
+            if ends_element && matches!(digit, SnailfishDigit::Number(_) | SnailfishDigit::OpenBrace) {
+                write!(f, ",")?;
+            }
+
+            match digit {
+                SnailfishDigit::OpenBrace => write!(f, "[")?,
+                SnailfishDigit::CloseBrace => write!(f, "]")?,
+                SnailfishDigit::Number(n) => write!(f, "{}", n)?,
+            }
+            previous = Some(*digit);
+        }
+
+        Ok(())
+    }
+}
+
+impl Add<SnailfishNumber> for SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    fn add(self, rhs: SnailfishNumber) -> Self::Output {
+        let digits = std::iter::once(SnailfishDigit::OpenBrace).chain(self.digits.clone()).chain(rhs.digits.clone()).chain(std::iter::once(SnailfishDigit::CloseBrace)).collect();
+        Self::Output { digits }.simplify()
+    }
+}
+
+impl Sum for SnailfishNumber {
+    fn sum<I: Iterator<Item = SnailfishNumber>>(iter: I) -> Self {
+        iter.reduce(|acc, i| acc + i).unwrap()
+    }
+}
+
+impl FromStr for SnailfishNumber {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, s)
+    }
+}
+
+// a little recursive descent parser, so a mistake gets pointed at where it is rather than
+// quietly skipped over
+struct Parser<'a> {
+    input: &'a str,
+    line: &'a str,
+    position: usize,
+    digits: Vec<SnailfishDigit>,
+}
+
+impl Parser<'_> {
+    fn next(&mut self) -> Option<char> {
+        while self.line[self.position..].starts_with(' ') {
+            self.position += 1;
+        }
+
+        self.line[self.position..].chars().next()
+    }
+
+    fn error(&self, expected: &str) -> ParseError {
+        let rest = &self.line[self.position..];
+        ParseError::at(self.input, &rest[..rest.chars().next().map_or(0, char::len_utf8)], expected)
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.next() != Some(c) {
+            return Err(self.error(&format!("`{}`", c)));
+        }
+
+        self.position += 1;
+        Ok(())
+    }
+
+    fn pair(&mut self) -> Result<(), ParseError> {
+        self.expect('[')?;
+        self.digits.push(SnailfishDigit::OpenBrace);
+        self.element()?;
+        self.expect(',')?;
+        self.element()?;
+        self.expect(']')?;
+        self.digits.push(SnailfishDigit::CloseBrace);
+        Ok(())
+    }
+
+    fn element(&mut self) -> Result<(), ParseError> {
+        match self.next() {
+            Some('[') => self.pair(),
+            Some(c) if c.is_ascii_digit() => {
+                self.digits.push(SnailfishDigit::Number(c.to_digit(10).unwrap() as u64));
+                self.position += 1;
+                Ok(())
+            }
+            _ => Err(self.error("a digit or a pair")),
+        }
+    }
+}
+
+impl SnailfishNumber {
+    // `line` is one line of `input`, which is just there so errors can say where they are
+    pub fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser { input, line, position: 0, digits: Vec::new() };
+        parser.pair()?;
+        if parser.next().is_some() {
+            return Err(parser.error("the end of the line"));
+        }
+
+        Ok(Self { digits: parser.digits })
+    }
+
+    pub fn simplify(&self) -> Self {
+        let mut last_step = self.clone();
+        let mut steps = 0;
+        'logic: loop {
+            let mut next_step = last_step.clone();
+            // find the first pair of two plain numbers nested four deep. adding reduced
+            // numbers only ever makes those, but the input can nest them deeper, so this
+            // goes for the innermost and works its way out
+            let mut nest_level = 0;
+            for (index, digit) in last_step.digits.iter().enumerate() {
+                let next = last_step.digits.get(index + 1);
+                match (digit, next) {
+                    (SnailfishDigit::OpenBrace, _) => nest_level += 1,
+                    (SnailfishDigit::CloseBrace, _) => nest_level -= 1,
+                    (SnailfishDigit::Number(x), Some(&SnailfishDigit::Number(y))) if nest_level >= 5 => {
+
+                        if let Some((i, e)) = next_step.digits[..index]
+                            .iter()
+                            .enumerate()
+                            .rfind(|(_, e)| matches!(e, SnailfishDigit::Number(_)))
+                        {
+                            let SnailfishDigit::Number(old) = e else {
+                                unreachable!();
+                            };
+                            next_step.digits[i] = SnailfishDigit::Number(old + x);
+                        }
+
+                        if let Some((i, e)) = next_step.digits[index + 2..]
+                            .iter()
+                            .enumerate()
+                            .find(|(_, e)| matches!(e, SnailfishDigit::Number(_)))
+                        {
+                            let SnailfishDigit::Number(old) = e else {
+                                unreachable!();
+                            };
+                            next_step.digits[i + index + 2] = SnailfishDigit::Number(old + y);
+                        }
+
+                        next_step.digits[index - 1] = SnailfishDigit::Number(0);
+                        next_step.digits.remove(index);
+                        next_step.digits.remove(index);
+                        next_step.digits.remove(index);
+
+                        last_step = next_step;
+                        steps += 1;
+                        trace::snapshot(18, format_args!("step {}: explode", steps), &last_step);
+                        continue 'logic;
+                    }
+                    _ => {}
+                }
+            }
+
+            // next check if any need to split
+            for (index, digit) in last_step.digits.iter().enumerate() {
+                match digit {
+                    SnailfishDigit::Number(x) if x >= &10 => {
+                        let new_left = x / 2;
+                        let new_right = x / 2 + x % 2;
+                        next_step.digits[index] = SnailfishDigit::CloseBrace;
+                        next_step.digits.insert(index, SnailfishDigit::Number(new_right));
+                        next_step.digits.insert(index, SnailfishDigit::Number(new_left));
+                        next_step.digits.insert(index, SnailfishDigit::OpenBrace);
+
+                        last_step = next_step;
+                        steps += 1;
+                        trace::snapshot(18, format_args!("step {}: split", steps), &last_step);
+                        continue 'logic;
+                    }
+                    _ => {}
+                }
+            }
+
+            break;
+        }
+
+        last_step
+    }
+
+    // reduced numbers can't get anywhere near overflowing, but one straight from the input
+    // hasn't been reduced, and can be nested as deep as it likes
+    pub fn magnitude(&self) -> Result<Count, Overflow> {
+        // the magnitudes of the elements we've finished, innermost last
+        let mut magnitudes = Vec::new();
+        for digit in &self.digits {
+            match digit {
+                SnailfishDigit::OpenBrace => {}
+                SnailfishDigit::Number(n) => magnitudes.push(Count::from(*n)),
+                SnailfishDigit::CloseBrace => {
+                    let right = magnitudes.pop().unwrap();
+                    let left = magnitudes.pop().unwrap();
+                    magnitudes.push(left.times(3)?.checked_add(&right.times(2)?)?);
+                }
+            }
+        }
+
+        Ok(magnitudes.pop().unwrap())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<SnailfishNumber>, ParseError> {
+    let numbers = parse::lines(input).map(|l| SnailfishNumber::parse(input, l)).collect::<Result<Vec<_>, _>>()?;
+    if numbers.is_empty() {
+        return Err(ParseError::at_end(input, "at least one snailfish number"));
+    }
+
+    Ok(numbers)
+}
+
+pub fn part_1(input: &[SnailfishNumber]) -> Result<Count, Overflow> {
+    input.iter().cloned().sum::<SnailfishNumber>().magnitude()
+}
+
+pub fn part_2(input: &[SnailfishNumber]) -> Result<Count, Overflow> {
+    let pairs = input.iter().tuple_combinations::<(_, _)>().collect::<Vec<_>>();
+    #[cfg(feature = "parallel")]
+    let pairs = pairs.into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let pairs = pairs.into_iter();
+
+    let magnitudes = pairs
+        .map(|(left, right)| {
+            let forwards = (left.clone() + right.clone()).magnitude()?;
+            let backwards = (right.clone() + left.clone()).magnitude()?;
+            Ok(forwards.max(backwards))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(magnitudes.into_iter().max().unwrap_or_default())
+}
+
+
+#[derive(Error, Debug)]
+pub enum SolutionError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<SnailfishNumber>;
+    type Output1 = Count;
+    type Output2 = Count;
+    type Error = SolutionError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part_1(input)?)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Ok(part_2(input)?)
+    }
+}
+
+#[test]
+pub fn test_magnitude() {
+    let magnitude = |s: &str| SnailfishNumber::from_str(s).unwrap().magnitude().unwrap().to_string();
+    assert_eq!(magnitude("[9,1]"), "29");
+    assert_eq!(magnitude("[[1,2],[[3,4],5]]"), "143");
+    assert_eq!(magnitude("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"), "3488");
+
+    // nothing's been reduced, so every level of nesting triples it
+    let deep = (0..50).fold("[9,9]".to_owned(), |inner, _| format!("[{},9]", inner));
+    let deep = SnailfishNumber::from_str(&deep).unwrap();
+    if common::count::UNBOUNDED {
+        assert!(deep.magnitude().unwrap() > Count::from(u64::MAX));
+    } else {
+        assert_eq!(deep.magnitude(), Err(Overflow));
+    }
+}
+
+#[test]
+pub fn test_simplify() {
+    let left = SnailfishNumber::from_str("[[[[4,3],4],4],[7,[[8,4],9]]]").unwrap();
+    let right = SnailfishNumber::from_str("[1,1]").unwrap();
+    let (sum, steps) = trace::record(|| left + right);
+    let steps = steps.iter().map(|s| format!("{} {}", s.step, s.state)).collect::<Vec<_>>();
+    assert_eq!(steps, vec![
+        "step 1: explode [[[[0,7],4],[7,[[8,4],9]]],[1,1]]",
+        "step 2: explode [[[[0,7],4],[15,[0,13]]],[1,1]]",
+        "step 3: split [[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+        "step 4: split [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+        "step 5: explode [[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+    ]);
+    assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+
+    assert_eq!(
+        SnailfishNumber::from_str("[[[[[9,8],1],2],3],4]")
+            .unwrap()
+            .simplify(),
+        SnailfishNumber::from_str("[[[[0, 9],2],3],4]").unwrap()
+    );
+
+    assert_eq!(
+        SnailfishNumber::from_str("[7,[6,[5,[4,[3,2]]]]]")
+            .unwrap()
+            .simplify(),
+        SnailfishNumber::from_str("[7,[6,[5,[7,0]]]]").unwrap()
+    );
+
+    assert_eq!(
+        SnailfishNumber::from_str("[[6,[5,[4,[3,2]]]],1]")
+            .unwrap()
+            .simplify(),
+        SnailfishNumber::from_str("[[6,[5,[7,0]]],3]").unwrap()
+    );
+
+    assert_eq!(
+        SnailfishNumber::from_str("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]")
+            .unwrap()
+            .simplify(),
+        SnailfishNumber::from_str("[[3,[2,[8,0]]],[9,[5,[7,0]]]]").unwrap()
+    );
+
+    assert_eq!(
+        SnailfishNumber::from_str("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]")
+            .unwrap()
+            .simplify(),
+        SnailfishNumber::from_str("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap()
+    );
+
+    // nested deeper than adding ever makes: [2,3] goes first, then the [3,0] it leaves
+    assert_eq!(
+        SnailfishNumber::from_str("[[[[[1,[2,3]],4],5],6],7]")
+            .unwrap()
+            .simplify(),
+        SnailfishNumber::from_str("[[[[0,7],5],6],7]").unwrap()
+    );
+    let deep = (0..10).fold("[1,2]".to_owned(), |inner, _| format!("[{},3]", inner));
+    let numbers = parse_input(&format!("{}\n[[[[[1,[2,3]],4],5],6],7]\n", deep)).unwrap();
+    assert!(part_1(&numbers).is_ok());
+    assert!(part_2(&numbers).is_ok());
+}
+
+#[test]
+pub fn test_parse_error() {
+    let error = parse_input("[1,2]\n[[3,4],x]").unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 8, "x"));
+    assert_eq!(error.expected, "a digit or a pair");
+
+    let error = SnailfishNumber::from_str("[1,2]]").unwrap_err();
+    assert_eq!((error.column, error.expected.as_str()), (6, "the end of the line"));
+}
+
+#[test]
+pub fn test_empty() {
+    for input in ["", "\n", " \n\n"] {
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(error.expected, "at least one snailfish number");
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use common::{Generate, Rng};
+
+use crate::{Coord, Day19};
+
+// scanners see everything up to this far away along each axis
+const RANGE: i64 = 1000;
+
+// how many beacons two scanners need in common before they can be lined up
+const OVERLAP: usize = 12;
+
+pub struct Scanners {
+    pub input: String,
+    pub beacons: usize,
+}
+
+fn in_range(scanner: (i64, i64, i64), beacon: (i64, i64, i64)) -> bool {
+    (beacon.0 - scanner.0).abs() <= RANGE
+        && (beacon.1 - scanner.1).abs() <= RANGE
+        && (beacon.2 - scanner.2).abs() <= RANGE
+}
+
+// `count` scanners with `per_scanner` beacons that only they can see. every scanner after
+// the first sits a bit over a scanner's range away from one that came before it, and the
+// two of them share twelve more beacons between them, so they can all be lined up into one
+// map. returns the puzzle along with how many beacons there really are
+pub fn scanners(count: usize, per_scanner: usize, seed: u64) -> Scanners {
+    let mut rng = Rng::new(seed);
+    let around = |rng: &mut Rng, (x, y, z): (i64, i64, i64), spread: i64| {
+        (x + rng.range(-spread..spread + 1), y + rng.range(-spread..spread + 1), z + rng.range(-spread..spread + 1))
+    };
+
+    let mut positions = vec![(0, 0, 0)];
+    let mut pairs = Vec::new();
+    while positions.len() < count.max(1) {
+        let parent = *rng.pick(&positions);
+        let mut position = around(&mut rng, parent, 150);
+        let step = rng.range(1100..1300) * if rng.chance(0.5) { 1 } else { -1 };
+        match rng.index(3) {
+            0 => position.0 += step,
+            1 => position.1 += step,
+            _ => position.2 += step,
+        }
+
+        // anything too close to another scanner would muddy the overlaps
+        if positions.iter().all(|&p| p == parent || !in_range(p, position)) {
+            pairs.push((parent, position));
+            positions.push(position);
+        }
+    }
+
+    let mut beacons = HashSet::new();
+    for &(a, b) in &pairs {
+        let middle = ((a.0 + b.0) / 2, (a.1 + b.1) / 2, (a.2 + b.2) / 2);
+        // the solution lines scanners up one axis at a time, so the shared beacons can't
+        // have any coordinates in common or it'd see fewer than twelve of them
+        let mut shared: Vec<(i64, i64, i64)> = Vec::new();
+        while shared.len() < OVERLAP {
+            let beacon = around(&mut rng, middle, 400);
+            let distinct = shared.iter().all(|s| {
+                let (xs, ys) = ([s.0, s.1, s.2], [beacon.0, beacon.1, beacon.2]);
+                xs.iter().all(|x| !ys.contains(x))
+            });
+            if in_range(a, beacon) && in_range(b, beacon) && distinct && !beacons.contains(&beacon) {
+                beacons.insert(beacon);
+                shared.push(beacon);
+            }
+        }
+    }
+
+    for &scanner in &positions {
+        // give up on a beacon rather than look forever if the others crowd this one out
+        for _ in 0..per_scanner {
+            let beacon = around(&mut rng, scanner, RANGE);
+            if positions.iter().all(|&p| p == scanner || !in_range(p, beacon)) {
+                beacons.insert(beacon);
+            }
+        }
+    }
+
+    let mut beacons = beacons.into_iter().collect::<Vec<_>>();
+    beacons.sort_unstable();
+
+    let reports = positions.iter().enumerate().map(|(i, &scanner)| {
+        // the first scanner's point of view is the one everything gets lined up to, so only
+        // the others get turned around
+        let orientation = if i == 0 { 0 } else { rng.index(24) };
+        let mut seen = beacons.iter()
+            .filter(|&&beacon| in_range(scanner, beacon))
+            .map(|&(x, y, z)| Coord::from((x - scanner.0, y - scanner.1, z - scanner.2)).under_all_orientations()[orientation])
+            .map(|c| format!("{},{},{}", c.x, c.y, c.z))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut seen);
+
+        format!("--- scanner {} ---\n{}", i, seen.join("\n"))
+    }).collect::<Vec<_>>();
+
+    Scanners { input: reports.join("\n\n"), beacons: beacons.len() }
+}
+
+impl Generate for Day19 {
+    // `size` scanners with 25 beacons of their own each, about as many as the real ones
+    fn generate(size: usize, seed: u64) -> String {
+        scanners(size, 25, seed).input
+    }
+}
+
+#[test]
+pub fn test_generate() {
+    use crate::{parse_input, part_1};
+
+    let scanners = scanners(3, 15, 1);
+    assert_eq!(part_1(&parse_input(&scanners.input).unwrap()), Ok(scanners.beacons));
+}
+
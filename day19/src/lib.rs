@@ -0,0 +1,285 @@
+use std::collections::{BTreeSet, HashSet};
+use std::ops::Add;
+use std::convert::Infallible;
+use itertools::Itertools;
+use thiserror::Error;
+use common::{parse, ParseError, Solution, Unsolved};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub mod generate;
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Coord {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Coord {
+    pub fn under_all_orientations(self) -> [Coord; 24] {
+        let Coord { x, y, z} = self;
+        [
+            (x, y, z).into(),
+            (x, z, -y).into(),
+            (x, -y, -z).into(),
+            (x, -z, y).into(),
+            (-x, y, -z).into(),
+            (-x, -z, -y).into(),
+            (-x, -y, z).into(),
+            (-x, z, y).into(),
+            (y, -x, z).into(),
+            (y, z, x).into(),
+            (y, x, -z).into(),
+            (y, -z, -x).into(),
+            (-y, -x, -z).into(),
+            (-y, -z, x).into(),
+            (-y, x, z).into(),
+            (-y, z, -x).into(),
+            (z, x, y).into(),
+            (z, y, -x).into(),
+            (z, -x, -y).into(),
+            (z, -y, x).into(),
+            (-z, y, x).into(),
+            (-z, x, -y).into(),
+            (-z, -y, -x).into(),
+            (-z, -x, y).into(),
+        ]
+    }
+}
+
+impl From<(i64, i64, i64)> for Coord {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Add<(i64, i64, i64)> for Coord {
+    type Output = Coord;
+
+    fn add(self, (x, y, z): (i64, i64, i64)) -> Self::Output {
+        Coord { x: self.x + x, y: self.y + y, z: self.z + z }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannerReport {
+    beacons: HashSet<Coord>
+}
+
+impl ScannerReport {
+    pub fn overlap_with(&self, other: &ScannerReport) -> Option<(ScannerReport, ScannerReport)> {
+        let orientations = other.beacons.iter().map(|c| c.under_all_orientations()).collect::<Vec<_>>();
+        let our_xs = self.beacons.iter().map(|c| c.x).collect::<BTreeSet<_>>();
+        let x_min = our_xs.first().unwrap();
+        let x_max = our_xs.last().unwrap();
+
+        for i in 0..24 {
+            let beacons_in_orientation = orientations.iter().map(|o| o[i]).collect::<HashSet<_>>();
+            let beacon_xs = beacons_in_orientation.iter().map(|c| c.x).collect::<BTreeSet<_>>();
+            let other_x_min = *beacon_xs.first().unwrap();
+            let other_x_max = *beacon_xs.last().unwrap();
+
+            for potential_dx in other_x_min - x_max ..= other_x_max - x_min {
+                let translated_xs = beacon_xs.iter().map(|x| x - potential_dx).collect::<BTreeSet<_>>();
+                let x_intersection = translated_xs.intersection(&our_xs).collect::<BTreeSet<_>>();
+                if x_intersection.len() < 12 {
+                    continue; 
+                }
+
+                // we might have found an overlap
+                // println!("With offset of {} we get x overlap", potential_dx);
+                let other_translated_beacons_in_x_overlap = beacons_in_orientation.iter().map(|b| *b + (-potential_dx, 0, 0)).filter(|c| x_intersection.contains(&c.x)).collect::<HashSet<_>>();
+                let our_beacons_in_x_overlap = self.beacons.iter().filter(|c| x_intersection.contains(&c.x)).collect::<HashSet<_>>();
+                let our_ys = our_beacons_in_x_overlap.iter().map(|c| c.y).collect::<BTreeSet<_>>();
+                // println!("Our beacons are {:?} with ys {:?}", our_beacons_in_x_overlap, our_ys);
+                let y_min = our_ys.first().unwrap();
+                let y_max = our_ys.last().unwrap();
+                let beacon_ys = other_translated_beacons_in_x_overlap.iter().map(|c| c.y).collect::<BTreeSet<_>>();
+                // println!("Other beacons are {:?} with ys {:?}", other_translated_beacons_in_x_overlap, beacon_ys);
+                let other_y_min = *beacon_ys.first().unwrap();
+                let other_y_max = *beacon_ys.last().unwrap();
+
+                for potential_dy in other_y_min - y_max ..= other_y_max - y_min {
+                    let translated_ys = beacon_ys.iter().map(|y| y - potential_dy).collect::<BTreeSet<_>>();
+                    let y_intersection = translated_ys.intersection(&our_ys).collect::<BTreeSet<_>>();
+                    if y_intersection.len() < 12 {
+                        // the x worked but this y didn't, alas
+                        continue; 
+                    }
+
+                    // we STILL might have found an overlap
+                    let other_translated_beacons_in_y_overlap = beacons_in_orientation.iter().map(|b| *b + (-potential_dx, -potential_dy, 0)).filter(|c| y_intersection.contains(&c.y) && x_intersection.contains(&c.x)).collect::<HashSet<_>>();
+                    let our_beacons_in_y_overlap = self.beacons.iter().filter(|c| y_intersection.contains(&c.y) && x_intersection.contains(&c.x)).collect::<HashSet<_>>();
+                    let our_zs = our_beacons_in_y_overlap.iter().map(|c| c.z).collect::<BTreeSet<_>>();
+                    let z_min = our_zs.first().unwrap();
+                    let z_max = our_zs.last().unwrap();
+                    let beacon_zs = other_translated_beacons_in_y_overlap.iter().map(|c| c.z).collect::<BTreeSet<_>>();
+                    let other_z_min = *beacon_zs.first().unwrap();
+                    let other_z_max = *beacon_zs.last().unwrap();
+
+                    for potential_dz in other_z_min - z_max ..= other_z_max - z_min {
+                        let translated_zs = beacon_zs.iter().map(|z| z - potential_dz).collect::<BTreeSet<_>>();
+                        let z_intersection = translated_zs.intersection(&our_zs).collect::<BTreeSet<_>>();
+                        if z_intersection.len() < 12 {
+                            // tx and y worked but z didn't, super alas
+                            continue; 
+                        }
+
+                        // println!("Found overlap of {:?}, {:?}, {:?} at {}, {}, {}", x_intersection, y_intersection, z_intersection, potential_dx, potential_dy, potential_dz);
+
+                        let mut other_beacons_translated = beacons_in_orientation.into_iter().map(|c| c + (- potential_dx, - potential_dy, - potential_dz)).collect::<HashSet<_>>();
+                        other_beacons_translated.extend(self.beacons.clone());
+                        let combined_report = ScannerReport { beacons: other_beacons_translated };
+                        let overlap = self.beacons.iter().copied().filter(|c| y_intersection.contains(&c.y) && x_intersection.contains(&c.x) && z_intersection.contains(&c.z)).collect::<HashSet<_>>();
+                        return Some((ScannerReport { beacons: overlap }, combined_report));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+// no pair of the groups the scanners merged into shared 12 beacons, so there's no telling
+// where they are relative to each other
+#[derive(Error, Clone, Copy, PartialEq, Eq, Debug)]
+#[error("the scanners only fit together into {groups} groups, with no 12 beacons in common between them")]
+pub struct Disjoint {
+    pub groups: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Input {
+    reports: Vec<ScannerReport>,
+}
+
+impl Input {
+    pub fn full_overlap(&self) -> Result<ScannerReport, Disjoint> {
+        let mut combined = self.reports.clone();
+        while combined.len() > 1 {
+            let pairs = combined.iter().enumerate().tuple_combinations::<(_, _)>().collect::<Vec<_>>();
+            let overlap = |((i, first), (j, second)): &((usize, &ScannerReport), (usize, &ScannerReport))| {
+                first.overlap_with(second).map(|(_, union)| (*i, *j, union))
+            };
+
+            // always merge the earliest overlapping pair, even if another thread found a later one first,
+            // so that the answer doesn't depend on how the threads were scheduled
+            #[cfg(feature = "parallel")]
+            let merge = pairs.par_iter().find_map_first(overlap);
+            #[cfg(not(feature = "parallel"))]
+            let merge = pairs.iter().find_map(overlap);
+
+            // a whole pass without merging anything means nothing else ever will
+            let Some((i, j, union)) = merge else {
+                return Err(Disjoint { groups: combined.len() });
+            };
+            combined[i] = union;
+            combined.remove(j);
+        }
+
+        Ok(ScannerReport { beacons: combined[0].beacons.clone() })
+    }
+}
+
+pub fn part_1(input: &Input) -> Result<usize, Disjoint> {
+    Ok(input.full_overlap()?.beacons.len())
+}
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut reports = Vec::new();
+    for scanner in parse::sections(input) {
+        let mut lines = parse::lines(scanner);
+        let header = lines.next().unwrap_or(scanner);
+        // the label doesn't matter (the tests write out overlaps the same way), nor does the
+        // number, as long as it is one
+        parse::header::<usize>(input, header)?;
+
+        let beacons = lines.map(|line| {
+            let [x, y, z] = parse::array(input, line, ",", "a beacon like `-1,2,3`", |n| parse::number(input, n))?;
+            Ok((x, y, z).into())
+        }).collect::<Result<HashSet<_>, _>>()?;
+        if beacons.is_empty() {
+            return Err(ParseError::at(input, header, "a scanner that can see at least one beacon"));
+        }
+
+        reports.push(ScannerReport { beacons });
+    }
+
+    if reports.is_empty() {
+        return Err(ParseError::at_end(input, "at least one scanner"));
+    }
+
+    Ok(Input { reports })
+}
+
+#[derive(Error, Debug)]
+pub enum SolutionError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Unsolved(#[from] Unsolved),
+    #[error(transparent)]
+    Disjoint(#[from] Disjoint),
+}
+
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = Infallible;
+    type Error = SolutionError;
+
+    fn parse_input(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Ok(part_1(input)?)
+    }
+
+    fn part_2(_: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Err(Unsolved.into())
+    }
+}
+
+#[test]
+pub fn test() {
+    let input = parse_input(include_str!("../fixtures/example.txt")).unwrap();
+    let overlap_relative_to_0 = &parse_input(include_str!("../fixtures/expected/overlap_0.txt")).unwrap().reports[0];
+    let overlap_relative_to_1 = &parse_input(include_str!("../fixtures/expected/overlap_1.txt")).unwrap().reports[0];
+    let scanner_0 = &input.reports[0];
+    let scanner_1 = &input.reports[1];
+    let get_overlap_0 = scanner_0.overlap_with(scanner_1).unwrap();
+    let get_overlap_1 = scanner_1.overlap_with(scanner_0).unwrap();
+    assert_eq!(&get_overlap_0.0, overlap_relative_to_0);
+    assert_eq!(&get_overlap_1.0, overlap_relative_to_1);
+}
+
+#[test]
+pub fn test_full() {
+    let input = parse_input(include_str!("../fixtures/example.txt")).unwrap();
+    let expected_overlap = &parse_input(include_str!("../fixtures/expected/full_overlap.txt")).unwrap().reports[0];
+    assert_eq!(&input.full_overlap().unwrap(), expected_overlap);
+}
+
+#[test]
+pub fn test_empty() {
+    for input in ["", "\n", " \n\n"] {
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(error.expected, "at least one scanner");
+    }
+}
+
+#[test]
+pub fn test_disjoint() {
+    // both parse fine, but two beacons each can't ever be 12 in common
+    let input = parse_input("--- scanner 0 ---\n0,0,0\n1,1,1\n\n--- scanner 1 ---\n500,500,500\n7,8,9\n").unwrap();
+    assert_eq!(input.full_overlap(), Err(Disjoint { groups: 2 }));
+    assert_eq!(part_1(&input), Err(Disjoint { groups: 2 }));
+}
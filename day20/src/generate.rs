@@ -0,0 +1,18 @@
+use common::{Generate, Rng};
+
+use crate::Day20;
+
+fn pixels(rng: &mut Rng, count: usize) -> String {
+    (0..count).map(|_| if rng.chance(0.5) { '#' } else { '.' }).collect()
+}
+
+impl Generate for Day20 {
+    // a 512 character enhancement algorithm and a square image `size` on each side
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let algorithm = pixels(&mut rng, 512);
+        let image = (0..size).map(|_| pixels(&mut rng, size)).collect::<Vec<_>>();
+
+        format!("{}\n\n{}", algorithm, image.join("\n"))
+    }
+}
@@ -0,0 +1,27 @@
+use std::convert::Infallible;
+use common::{Solution, Unsolved};
+
+pub mod generate;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = ();
+    type Output1 = Infallible;
+    type Output2 = Infallible;
+    type Error = Unsolved;
+
+    fn parse_input(_: &str) -> Result<Self::Input, Self::Error> {
+        Ok(())
+    }
+
+    fn part_1(_: &Self::Input) -> Result<Self::Output1, Self::Error> {
+        Err(Unsolved)
+    }
+
+    fn part_2(_: &Self::Input) -> Result<Self::Output2, Self::Error> {
+        Err(Unsolved)
+    }
+}
@@ -0,0 +1,312 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// a node, as handed out by the graph it's in. they're numbered from 0 in the order the nodes
+// were added, so anything per node can live in a Vec instead of a HashMap
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NodeId(pub usize);
+
+// a directed graph as adjacency lists, with a weight of type `W` on each edge. nodes are
+// interned: adding the same `N` twice gives the same id back. graphs where every edge costs
+// the same can leave `W` as ()
+#[derive(Clone, Debug)]
+pub struct Graph<N, W = ()> {
+    nodes: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    edges: Vec<Vec<(NodeId, W)>>,
+}
+
+impl<N: Clone + Eq + Hash, W> Default for Graph<N, W> {
+    fn default() -> Self {
+        Graph { nodes: Vec::new(), ids: HashMap::new(), edges: Vec::new() }
+    }
+}
+
+impl<N: Clone + Eq + Hash, W> Graph<N, W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(nodes: usize) -> Self {
+        Graph { nodes: Vec::with_capacity(nodes), ids: HashMap::with_capacity(nodes), edges: Vec::with_capacity(nodes) }
+    }
+
+    pub fn add_node(&mut self, node: N) -> NodeId {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(node.clone());
+        self.ids.insert(node, id);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    // an edge one way only
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: W) {
+        self.edges[from.0].push((to, weight));
+    }
+
+    // an edge each way, with the same weight
+    pub fn connect(&mut self, a: NodeId, b: NodeId, weight: W) where W: Clone {
+        self.edges[a.0].push((b, weight.clone()));
+        self.edges[b.0].push((a, weight));
+    }
+
+    pub fn id(&self, node: &N) -> Option<NodeId> {
+        self.ids.get(node).copied()
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id.0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &N)> {
+        self.nodes.iter().enumerate().map(|(i, node)| (NodeId(i), node))
+    }
+
+    pub fn edges(&self, from: NodeId) -> impl Iterator<Item = (NodeId, &W)> {
+        self.edges[from.0].iter().map(|(to, weight)| (*to, weight))
+    }
+
+    pub fn neighbours(&self, from: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges[from.0].iter().map(|&(to, _)| to)
+    }
+
+    // the fewest edges it takes to get to each node from `start`, ignoring the weights
+    pub fn bfs(&self, start: NodeId) -> Search<usize> {
+        let mut search = Search::new(self.len());
+        search.costs[start.0] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            let steps = search.costs[node.0].unwrap_or_default() + 1;
+            for neighbour in self.neighbours(node) {
+                if search.costs[neighbour.0].is_none() {
+                    search.costs[neighbour.0] = Some(steps);
+                    search.predecessors[neighbour.0] = Some(node);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        search
+    }
+
+    // every path from `start` to `end`, depth first. `allowed` is asked about each step, given
+    // the path so far (which starts with `start`) and the node it'd go to next, so it decides
+    // what can be visited again. a path stops as soon as it gets to `end`
+    pub fn paths(&self, start: NodeId, end: NodeId, mut allowed: impl FnMut(&[NodeId], NodeId) -> bool) -> Vec<Vec<NodeId>> {
+        let mut paths = Vec::new();
+        let mut path = vec![start];
+        self.paths_from(end, &mut path, &mut allowed, &mut paths);
+        paths
+    }
+
+    fn paths_from(&self, end: NodeId, path: &mut Vec<NodeId>,
+        allowed: &mut impl FnMut(&[NodeId], NodeId) -> bool, paths: &mut Vec<Vec<NodeId>>
+    ) {
+        let node = *path.last().unwrap();
+        if node == end {
+            paths.push(path.clone());
+            return;
+        }
+
+        for next in self.neighbours(node) {
+            if allowed(path, next) {
+                path.push(next);
+                self.paths_from(end, path, allowed, paths);
+                path.pop();
+            }
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash, W: Copy + Ord + Add<Output = W> + Default> Graph<N, W> {
+    // the cheapest way to every node that can be reached from `start`
+    pub fn dijkstra(&self, start: NodeId) -> Search<W> {
+        self.cheapest(start, None, |_| W::default())
+    }
+
+    // the cheapest way to `goal`, looking first where `estimate` says it'll be cheapest to get
+    // there from. the estimate mustn't ever be more than it'd really cost, or the answer might
+    // not be the cheapest. it doesn't have to be consistent from one node to the next, though:
+    // if a cheaper way to a node turns up after it's been done, it's done again. only `goal`
+    // is sure to be at its cheapest once it's found
+    pub fn astar(&self, start: NodeId, goal: NodeId, mut estimate: impl FnMut(&N) -> W) -> Search<W> {
+        self.cheapest(start, Some(goal), |id| estimate(&self.nodes[id.0]))
+    }
+
+    fn cheapest(&self, start: NodeId, goal: Option<NodeId>, mut estimate: impl FnMut(NodeId) -> W) -> Search<W> {
+        let mut search = Search::new(self.len());
+        // cheapest known so far for each node, and how it was got to
+        let mut best = vec![None; self.len()];
+        best[start.0] = Some((W::default(), None));
+
+        let mut frontier = BinaryHeap::new();
+        frontier.push(Reverse((estimate(start), W::default(), start)));
+        while let Some(Reverse((_, cost, node))) = frontier.pop() {
+            if best[node.0].is_some_and(|(cheapest, _)| cheapest < cost) || search.costs[node.0] == Some(cost) {
+                // a cheaper way here turned up after this was queued, or this way's already done
+                continue;
+            }
+
+            search.costs[node.0] = Some(cost);
+            search.predecessors[node.0] = best[node.0].and_then(|(_, from)| from);
+            if Some(node) == goal {
+                break;
+            }
+
+            // a neighbour that's already done gets done again if this way's cheaper, which only
+            // happens with an inconsistent estimate
+            for (neighbour, &weight) in self.edges(node) {
+                let cost_this_way = cost + weight;
+                match best[neighbour.0] {
+                    // the existing way is still the cheapest, no need to adjust anything
+                    Some((existing, _)) if existing <= cost_this_way => (),
+                    _ => {
+                        best[neighbour.0] = Some((cost_this_way, Some(node)));
+                        frontier.push(Reverse((cost_this_way + estimate(neighbour), cost_this_way, neighbour)));
+                    }
+                }
+            }
+        }
+
+        search
+    }
+}
+
+// what a search found out: how much it costs to get to each node, and the node before it on
+// the way there (the predecessor map), for every node it finished with
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Search<W> {
+    costs: Vec<Option<W>>,
+    predecessors: Vec<Option<NodeId>>,
+}
+
+impl<W: Copy> Search<W> {
+    fn new(len: usize) -> Self {
+        Search { costs: vec![None; len], predecessors: vec![None; len] }
+    }
+
+    pub fn cost(&self, node: NodeId) -> Option<W> {
+        self.costs[node.0]
+    }
+
+    // none for the start, as well as for anywhere it didn't get to
+    pub fn predecessor(&self, node: NodeId) -> Option<NodeId> {
+        self.predecessors[node.0]
+    }
+
+    pub fn predecessors(&self) -> &[Option<NodeId>] {
+        &self.predecessors
+    }
+
+    // the way it found to `node`, from the start to `node` inclusive
+    pub fn path_to(&self, node: NodeId) -> Option<Vec<NodeId>> {
+        self.costs[node.0]?;
+        let mut path = vec![node];
+        while let Some(previous) = self.predecessors[path[path.len() - 1].0] {
+            path.push(previous);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+fn example() -> Graph<&'static str, u32> {
+    // a -1- b -1- c -1- d, with a shortcut a -5- d and a dead end e off b
+    let mut graph = Graph::new();
+    for (a, b, weight) in [("a", "b", 1), ("b", "c", 1), ("c", "d", 1), ("a", "d", 5), ("b", "e", 1)] {
+        let (a, b) = (graph.add_node(a), graph.add_node(b));
+        graph.connect(a, b, weight);
+    }
+    graph.add_node("f");
+    graph
+}
+
+#[test]
+pub fn test_graph() {
+    let graph = example();
+    let id = |name| graph.id(&name).unwrap();
+    assert_eq!(graph.len(), 6);
+    assert_eq!(graph.clone().add_node("a"), NodeId(0));
+    assert_eq!(graph.id(&"g"), None);
+    assert_eq!(graph.neighbours(id("b")).map(|n| *graph.node(n)).collect::<Vec<_>>(), ["a", "c", "e"]);
+
+    let names = |path: Vec<NodeId>| path.into_iter().map(|n| *graph.node(n)).collect::<Vec<_>>();
+    let search = graph.bfs(id("a"));
+    assert_eq!(search.cost(id("d")), Some(1));
+    assert_eq!(search.cost(id("e")), Some(2));
+    assert_eq!(search.cost(id("f")), None);
+    assert_eq!(search.path_to(id("e")).map(names), Some(vec!["a", "b", "e"]));
+    assert_eq!(search.predecessor(id("a")), None);
+}
+
+#[test]
+pub fn test_cheapest() {
+    let graph = example();
+    let id = |name| graph.id(&name).unwrap();
+    let names = |path: Vec<NodeId>| path.into_iter().map(|n| *graph.node(n)).collect::<Vec<_>>();
+
+    let search = graph.dijkstra(id("a"));
+    assert_eq!(search.cost(id("d")), Some(3));
+    assert_eq!(search.path_to(id("d")).map(names), Some(vec!["a", "b", "c", "d"]));
+    assert_eq!(search.path_to(id("f")), None);
+    assert_eq!(search.predecessors().iter().filter(|p| p.is_some()).count(), 4);
+
+    // with an estimate of how far each node really is from d, it never needs to finish with e
+    let remaining = HashMap::from([("a", 3), ("b", 2), ("c", 1), ("d", 0), ("e", 3), ("f", 0)]);
+    let search = graph.astar(id("a"), id("d"), |node| remaining[node]);
+    assert_eq!(search.cost(id("d")), Some(3));
+    assert_eq!(search.cost(id("e")), None);
+    assert_eq!(search.path_to(id("d")).map(names), Some(vec!["a", "b", "c", "d"]));
+}
+
+#[test]
+pub fn test_paths() {
+    let graph = example();
+    let id = |name| graph.id(&name).unwrap();
+    let simple = graph.paths(id("a"), id("d"), |path, next| !path.contains(&next));
+    assert_eq!(simple.len(), 2);
+    assert!(simple.contains(&vec![id("a"), id("d")]));
+
+    // letting b be gone through twice adds the detours to e and back
+    let twice = graph.paths(id("a"), id("d"), |path, next| {
+        !path.contains(&next) || (next == id("b") && path.iter().filter(|&&n| n == next).count() < 2)
+    });
+    assert_eq!(twice.len(), 3);
+    assert!(twice.contains(&vec![id("a"), id("b"), id("e"), id("b"), id("c"), id("d")]));
+    assert!(graph.paths(id("a"), id("f"), |path, next| !path.contains(&next)).is_empty());
+}
+
+#[test]
+pub fn test_inconsistent() {
+    // s -1- a -1- c -5- g, and s -1- b -3- c. the estimate from a is right, but says going
+    // through a costs 5 more than going through c, so c is done via b first
+    let mut graph = Graph::new();
+    for (from, to, weight) in [("s", "a", 1), ("s", "b", 1), ("a", "c", 1), ("b", "c", 3), ("c", "g", 5)] {
+        let (from, to) = (graph.add_node(from), graph.add_node(to));
+        graph.add_edge(from, to, weight);
+    }
+    let id = |name| graph.id(&name).unwrap();
+
+    let search = graph.astar(id("s"), id("g"), |&node| if node == "a" { 6 } else { 0 });
+    assert_eq!(search.cost(id("g")), Some(7));
+    assert_eq!(search.path_to(id("g")).unwrap(), [id("s"), id("a"), id("c"), id("g")]);
+}
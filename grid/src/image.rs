@@ -0,0 +1,247 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use common::Solution;
+use thiserror::Error;
+
+use crate::{Grid, Point};
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    pub fn luminance(self) -> u8 {
+        let Rgb(r, g, b) = self;
+        (0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64).round() as u8
+    }
+
+    fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(channel(self.0, other.0), channel(self.1, other.1), channel(self.2, other.2))
+    }
+
+    // as many colours as you like that are easy to tell apart from their neighbours, for
+    // things like day 9's basins. goes round the colour wheel by the golden angle
+    pub fn distinct(i: usize) -> Rgb {
+        let hue = (i as f64 * 0.618_033_988_75).fract() * 6.0;
+        let rising = (255.0 * hue.fract()).round() as u8;
+        let falling = 255 - rising;
+        match hue as u8 {
+            0 => Rgb(255, rising, 0),
+            1 => Rgb(falling, 255, 0),
+            2 => Rgb(0, 255, rising),
+            3 => Rgb(0, falling, 255),
+            4 => Rgb(rising, 0, 255),
+            _ => Rgb(255, 0, falling),
+        }
+    }
+}
+
+// how to turn a number into a colour, from the smallest value (0.0) to the biggest (1.0)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Palette {
+    Grayscale,
+    // black, through red and yellow, to white
+    Heat,
+    // dark blue, through green, to yellow: easier on the eyes for maps
+    Viridis,
+}
+
+impl Palette {
+    fn stops(self) -> &'static [Rgb] {
+        match self {
+            Palette::Grayscale => &[Rgb::BLACK, Rgb::WHITE],
+            Palette::Heat => &[Rgb::BLACK, Rgb(230, 0, 0), Rgb(255, 220, 0), Rgb::WHITE],
+            Palette::Viridis => &[Rgb(68, 1, 84), Rgb(59, 82, 139), Rgb(33, 145, 140), Rgb(94, 201, 98), Rgb(253, 231, 37)],
+        }
+    }
+
+    pub fn colour(self, t: f64) -> Rgb {
+        let stops = self.stops();
+        let position = t.clamp(0.0, 1.0) * (stops.len() - 1) as f64;
+        let i = (position as usize).min(stops.len() - 2);
+        stops[i].mix(stops[i + 1], position - i as f64)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    // black and white only
+    Pbm,
+    Pgm,
+    Ppm,
+    Png,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "pbm" => Some(Format::Pbm),
+            "pgm" => Some(Format::Pgm),
+            "ppm" => Some(Format::Ppm),
+            "png" => Some(Format::Png),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ImageError {
+    #[error("don't know how to write {}: use .pbm, .pgm, .ppm or .png", .0.display())]
+    UnknownFormat(PathBuf),
+    #[error("can't write {}: PNG support needs the `png` feature", .0.display())]
+    NoPng(PathBuf),
+    #[error("failed to write {}: {source}", path.display())]
+    Unwritable { path: PathBuf, source: io::Error },
+}
+
+// a picture of a grid, one pixel per cell (until it's `scaled`)
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Image {
+    pixels: Grid<Rgb>,
+}
+
+impl Image {
+    pub fn from_grid<T>(grid: &Grid<T>, colour: impl FnMut(&T) -> Rgb) -> Image {
+        Image { pixels: grid.map(colour) }
+    }
+
+    // like ink on paper: black where it's set, white where it isn't
+    pub fn from_bools(grid: &Grid<bool>) -> Image {
+        Image::from_grid(grid, |&set| if set { Rgb::BLACK } else { Rgb::WHITE })
+    }
+
+    // scaled so that the smallest value gets one end of the palette, and the biggest the other
+    pub fn from_values<T: Copy + Into<f64>>(grid: &Grid<T>, palette: Palette) -> Image {
+        let (min, max) = grid.values().map(|&v| v.into()).fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| (min.min(v), max.max(v)));
+        let range = if max > min { max - min } else { 1.0 };
+        Image::from_grid(grid, |&v| palette.colour((v.into() - min) / range))
+    }
+
+    // just big enough to hold all the points
+    pub fn from_points(points: impl IntoIterator<Item = Point>) -> Image {
+        Image::from_bools(&Grid::from_sparse(points.into_iter().map(|p| (p, true)), false))
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    pub fn pixel(&self, point: Point) -> Option<Rgb> {
+        self.pixels.get(point).copied()
+    }
+
+    // each pixel becomes a `factor` by `factor` square, for pictures too small to make out
+    pub fn scaled(&self, factor: usize) -> Image {
+        let pixels = Grid::from_fn(self.width() * factor, self.height() * factor, |Point { x, y }| {
+            self.pixels[Point { x: x / factor, y: y / factor }]
+        });
+        Image { pixels }
+    }
+
+    pub fn write_pbm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P4\n{} {}\n", self.width(), self.height())?;
+        for row in self.pixels.rows() {
+            // eight pixels to a byte, with 1 for black
+            for chunk in row.chunks(8) {
+                let byte = chunk.iter().enumerate()
+                    .filter(|(_, pixel)| pixel.luminance() < 128)
+                    .fold(0u8, |byte, (i, _)| byte | (0x80 >> i));
+                out.write_all(&[byte])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn write_pgm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P5\n{} {}\n255\n", self.width(), self.height())?;
+        out.write_all(&self.pixels.values().map(|pixel| pixel.luminance()).collect::<Vec<_>>())
+    }
+
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width(), self.height())?;
+        out.write_all(&self.pixels.values().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect::<Vec<_>>())
+    }
+
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width() as u32, self.height() as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let data = self.pixels.values().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect::<Vec<_>>();
+        encoder.write_header()?.write_image_data(&data)?;
+        Ok(())
+    }
+
+    // in whichever format the extension asks for
+    pub fn save(&self, path: &Path) -> Result<(), ImageError> {
+        let format = Format::from_path(path).ok_or_else(|| ImageError::UnknownFormat(path.to_owned()))?;
+        if format == Format::Png && cfg!(not(feature = "png")) {
+            return Err(ImageError::NoPng(path.to_owned()));
+        }
+
+        let unwritable = |source| ImageError::Unwritable { path: path.to_owned(), source };
+        let mut out = BufWriter::new(File::create(path).map_err(unwritable)?);
+        match format {
+            Format::Pbm => self.write_pbm(&mut out),
+            Format::Pgm => self.write_pgm(&mut out),
+            Format::Ppm => self.write_ppm(&mut out),
+            #[cfg(feature = "png")]
+            Format::Png => self.write_png(&mut out),
+            #[cfg(not(feature = "png"))]
+            Format::Png => unreachable!(),
+        }.map_err(unwritable)?;
+        out.flush().map_err(unwritable)
+    }
+}
+
+// days with something worth looking at, for `aoc run --image`
+pub trait Render: Solution {
+    fn render(input: &Self::Input) -> Result<Image, Self::Error>;
+}
+
+#[test]
+pub fn test_formats() {
+    let image = Image::from_points([Point { x: 0, y: 0 }, Point { x: 9, y: 1 }]);
+    assert_eq!((image.width(), image.height()), (10, 2));
+
+    let mut pbm = Vec::new();
+    image.write_pbm(&mut pbm).unwrap();
+    assert_eq!(pbm, b"P4\n10 2\n\x80\x00\x00\x40");
+
+    let mut pgm = Vec::new();
+    image.write_pgm(&mut pgm).unwrap();
+    assert_eq!(&pgm[..13], b"P5\n10 2\n255\n\x00");
+    assert_eq!(pgm.len(), 12 + 20);
+
+    let mut ppm = Vec::new();
+    image.scaled(2).write_ppm(&mut ppm).unwrap();
+    assert_eq!(&ppm[..12], b"P6\n20 4\n255\n");
+    assert_eq!(ppm.len(), 12 + 20 * 4 * 3);
+
+    assert_eq!(Format::from_path(Path::new("out.PPM")), Some(Format::Ppm));
+    assert_eq!(Format::from_path(Path::new("out.jpg")), None);
+}
+
+#[test]
+pub fn test_palettes() {
+    let grid = Grid::from_rows(vec![vec![1u32, 5, 9]]).unwrap();
+    let image = Image::from_values(&grid, Palette::Grayscale);
+    assert_eq!(image.pixel(Point { x: 0, y: 0 }), Some(Rgb::BLACK));
+    assert_eq!(image.pixel(Point { x: 1, y: 0 }), Some(Rgb(128, 128, 128)));
+    assert_eq!(image.pixel(Point { x: 2, y: 0 }), Some(Rgb::WHITE));
+
+    assert_eq!(Palette::Heat.colour(0.0), Rgb::BLACK);
+    assert_eq!(Palette::Heat.colour(1.0), Rgb::WHITE);
+    assert_eq!(Palette::Viridis.colour(2.0), Rgb(253, 231, 37));
+    assert_ne!(Rgb::distinct(0), Rgb::distinct(1));
+}
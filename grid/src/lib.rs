@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use common::{parse, ParseError};
+use thiserror::Error;
+
+pub mod image;
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    #[error("row {row} is {found} wide, but the rows before it are {expected}")]
+    Ragged { row: usize, expected: usize, found: usize },
+}
+
+// a dense, row-major 2D grid. (0, 0) is the top left, and y goes down the page
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const ALL_AROUND: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self where T: Clone {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| Point { x, y })).map(&mut f).collect();
+        Grid { width, height, cells }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (row, values) in rows.into_iter().enumerate() {
+            if values.len() != width {
+                return Err(GridError::Ragged { row, expected: width, found: values.len() });
+            }
+            cells.extend(values);
+        }
+
+        Ok(Grid { width, height, cells })
+    }
+
+    // one row per line, one cell per character, where `cell` says what each character means
+    // (and `expected` describes the ones it likes). blank lines at either end are skipped, so
+    // a trailing newline doesn't count as an empty row, and so is whitespace on the end of
+    // each line, so it doesn't count as cells
+    pub fn parse(input: &str, expected: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in parse::lines(input).map(str::trim_end) {
+            cells.extend(parse::chars(input, line, expected, &mut cell)?);
+
+            let row_width = line.chars().count();
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(ParseError::at(input, line, format!("a row {} wide, like the ones before it", width.unwrap())));
+            }
+            height += 1;
+        }
+
+        Ok(Grid { width: width.unwrap_or(0), height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            self.cells.get(point.y * self.width + point.x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            self.cells.get_mut(point.y * self.width + point.x)
+        } else {
+            None
+        }
+    }
+
+    // every point in the grid, a row at a time
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero, and a zero-width grid has no rows worth showing anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    fn offsets<'a>(&self, point: Point, offsets: &'a [(isize, isize)]) -> impl Iterator<Item = Point> + 'a {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = point.x.checked_add_signed(dx).filter(|&x| x < width)?;
+            let y = point.y.checked_add_signed(dy).filter(|&y| y < height)?;
+            Some(Point { x, y })
+        })
+    }
+
+    // up, left, right and down, leaving out anything off the edge
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.offsets(point, &ORTHOGONAL)
+    }
+
+    // the neighbours plus the diagonals, leaving out anything off the edge
+    pub fn neighbours_diagonal(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.offsets(point, &ALL_AROUND)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    // each cell as a single character, one row per line
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut rendered = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            rendered.extend(row.iter().map(&mut cell));
+            rendered.push('\n');
+        }
+
+        rendered
+    }
+
+    // just big enough to hold every point given, with everything else set to `fill`
+    pub fn from_sparse(cells: impl IntoIterator<Item = (Point, T)>, fill: T) -> Self where T: Clone {
+        let cells = cells.into_iter().collect::<Vec<_>>();
+        let width = cells.iter().map(|(p, _)| p.x + 1).max().unwrap_or(0);
+        let height = cells.iter().map(|(p, _)| p.y + 1).max().unwrap_or(0);
+        let mut grid = Grid::new(width, height, fill);
+        for (point, value) in cells {
+            grid[point] = value;
+        }
+
+        grid
+    }
+
+    pub fn to_sparse(&self, mut keep: impl FnMut(&T) -> bool) -> HashMap<Point, T> where T: Clone {
+        self.iter().filter(|(_, value)| keep(value)).map(|(point, value)| (point, value.clone())).collect()
+    }
+}
+
+impl Grid<u32> {
+    pub fn parse_digits(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        assert!(self.contains(point), "{:?} is outside a {}x{} grid", point, self.width, self.height);
+        &self.cells[point.y * self.width + point.x]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        assert!(self.contains(point), "{:?} is outside a {}x{} grid", point, self.width, self.height);
+        &mut self.cells[point.y * self.width + point.x]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+pub fn test_parse() {
+    let grid = Grid::parse_digits("123\n456\n").unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point { x: 2, y: 1 }], 6);
+    assert_eq!(grid.get(Point { x: 3, y: 0 }), None);
+    assert_eq!(grid.to_string(), "123\n456\n");
+
+    assert_eq!(Grid::parse_digits("123 \n456\t\r\n").unwrap(), grid);
+
+    let error = Grid::parse_digits("12\n345").unwrap_err();
+    assert_eq!((error.line, error.column, error.expected.as_str()), (2, 1, "a row 2 wide, like the ones before it"));
+    let error = Grid::parse_digits("12\n3x").unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 2, "x"));
+
+    let grid = Grid::from_rows(vec![vec![1, 2], vec![3]]);
+    assert_eq!(grid, Err(GridError::Ragged { row: 1, expected: 2, found: 1 }));
+}
+
+#[test]
+pub fn test_neighbours() {
+    let grid = Grid::new(3, 3, 0);
+    assert_eq!(grid.neighbours((0, 0).into()).collect::<Vec<_>>(), vec![(1, 0).into(), (0, 1).into()]);
+    assert_eq!(grid.neighbours((1, 1).into()).count(), 4);
+    assert_eq!(grid.neighbours_diagonal((2, 2).into()).count(), 3);
+    assert_eq!(grid.neighbours_diagonal((1, 1).into()).count(), 8);
+    assert_eq!(grid.neighbours_diagonal((1, 0).into()).count(), 5);
+}
+
+#[test]
+pub fn test_sparse() {
+    let grid = Grid::from_sparse([((0, 1).into(), true), ((2, 0).into(), true)], false);
+    assert_eq!(grid.render(|&on| if on { '#' } else { '.' }), "..#\n#..\n");
+    assert_eq!(grid.to_sparse(|&on| on).len(), 2);
+    assert_eq!(Grid::from_sparse(grid.to_sparse(|&on| on), false), grid);
+}